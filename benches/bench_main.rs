@@ -12,10 +12,14 @@
 
 use criterion::*;
 
-use chrono::Utc;
 use rand::Rng;
+use std::time::{SystemTime, UNIX_EPOCH};
 use ulid_generator_rs::*;
 
+fn now_millis() -> u64 {
+  SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64
+}
+
 #[inline]
 fn j5ik2o_uild_gen(gen: &mut ULIDGenerator) {
   gen.generate();
@@ -39,39 +43,39 @@ fn j5ik2o_ulid_gen_to_string(gen: &mut ULIDGenerator) {
 /// dylanhart/ulid-rs
 #[inline]
 fn dylanhart_ulid_rs_gen_ulid() {
-  ulid::Ulid::new();
+  ::ulid::Ulid::new();
 }
 
 #[inline]
-fn dylanhart_ulid_rs_to_string(ulid: &ulid::Ulid) {
+fn dylanhart_ulid_rs_to_string(ulid: &::ulid::Ulid) {
   ulid.to_string();
 }
 
 #[inline]
 fn dylanhart_ulid_rs_gen_to_string() {
-  ulid::Ulid::new().to_string();
+  ::ulid::Ulid::new().to_string();
 }
 
 /// huxi/rusty_ulid
 #[inline]
 fn huxi_rusty_ulid_gen_ulid() {
-  rusty_ulid::Ulid::generate();
+  ::rusty_ulid::Ulid::generate();
 }
 
 #[inline]
-fn huxi_rusty_ulid_to_string(ulid: &rusty_ulid::Ulid) {
+fn huxi_rusty_ulid_to_string(ulid: &::rusty_ulid::Ulid) {
   ulid.to_string();
 }
 
 #[inline]
 fn huxi_rusty_ulid_gen_to_string() {
-  rusty_ulid::Ulid::generate().to_string();
+  ::rusty_ulid::Ulid::generate().to_string();
 }
 
 /// suyash/ulid-rs
 #[inline]
 fn suyash_ulid_rs_gen_ulid() {
-  ulid_rs::Ulid::new(Utc::now().timestamp_millis() as u64, || rand::thread_rng().gen::<u8>());
+  ulid_rs::Ulid::new(now_millis(), || rand::thread_rng().gen::<u8>());
 }
 
 #[inline]
@@ -81,7 +85,7 @@ fn suyash_ulid_rs_to_string(ulid: &ulid_rs::Ulid) {
 
 #[inline]
 fn suyash_ulid_rs_gen_to_string() {
-  ulid_rs::Ulid::new(Utc::now().timestamp_millis() as u64, || rand::thread_rng().gen::<u8>()).to_string();
+  ulid_rs::Ulid::new(now_millis(), || rand::thread_rng().gen::<u8>()).to_string();
 }
 
 fn criterion_benchmark(c: &mut Criterion) {
@@ -109,15 +113,15 @@ fn criterion_benchmark(c: &mut Criterion) {
   // group.bench_with_input(BenchmarkId::new("j5ik2o/ulid-generator-rs/str", op), &op, |b, i| {
   //   b.iter(|| j5ik2o_ulid_to_string(&ulid))
   // });
-  // let ulid = ulid::Ulid::new();
+  // let ulid = ::ulid::Ulid::new();
   // group.bench_with_input(BenchmarkId::new("dylanhart/ulid-rs/str", op), &op, |b, i| {
   //   b.iter(|| dylanhart_ulid_rs_to_string(&ulid))
   // });
-  // let ulid = rusty_ulid::Ulid::generate();
+  // let ulid = ::rusty_ulid::Ulid::generate();
   // group.bench_with_input(BenchmarkId::new("huxi/rusty_ulid/str", op), &op, |b, i| {
   //   b.iter(|| huxi_rusty_ulid_to_string(&ulid))
   // });
-  // let ulid = ulid_rs::Ulid::new(Utc::now().timestamp_millis() as u64, || rand::thread_rng().gen::<u8>());
+  // let ulid = ulid_rs::Ulid::new(now_millis(), || rand::thread_rng().gen::<u8>());
   // group.bench_with_input(BenchmarkId::new("suyash/ulid-rs/str", op), &op, |b, i| {
   //   b.iter(|| suyash_ulid_rs_to_string(&ulid))
   // });
@@ -128,13 +132,13 @@ fn criterion_benchmark(c: &mut Criterion) {
     |b, i| b.iter(|| j5ik2o_ulid_gen_to_string(&mut gen)),
   );
   group.bench_with_input(BenchmarkId::new("dylanhart/ulid-rs/gen_to_str", op), &op, |b, i| {
-    b.iter(|| dylanhart_ulid_rs_gen_to_string())
+    b.iter(dylanhart_ulid_rs_gen_to_string)
   });
   group.bench_with_input(BenchmarkId::new("huxi/rusty_ulid/gen_to_str", op), &op, |b, i| {
-    b.iter(|| huxi_rusty_ulid_gen_to_string())
+    b.iter(huxi_rusty_ulid_gen_to_string)
   });
   group.bench_with_input(BenchmarkId::new("suyash/ulid-rs/gen_to_str", op), &op, |b, i| {
-    b.iter(|| suyash_ulid_rs_gen_to_string())
+    b.iter(suyash_ulid_rs_gen_to_string)
   });
   group.finish();
 }
@@ -0,0 +1,186 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [actix-web](https://github.com/actix/actix-web) support.
+//!
+//! [ULIDPath] extracts a [ULID] from the single path parameter of a route, and [ULIDQuery] (with the `serde`
+//! feature) deserializes query parameters containing [ULID]s. Both reject with a [ULIDRejection], a JSON
+//! response such as `{"error":"invalid_ulid","message":"invalid the char: U"}`.
+//!
+//! ```rust
+//! use actix_web::{web, App};
+//! use ulid_generator_rs::actix_web::ULIDPath;
+//!
+//! async fn show(ULIDPath(id): ULIDPath) -> String {
+//!   id.to_string()
+//! }
+//!
+//! let app = App::new().route("/users/{id}", web::get().to(show));
+//! ```
+
+use std::future::{ready, Ready};
+
+use actix_web::dev::Payload;
+#[cfg(feature = "serde")]
+use actix_web::error::QueryPayloadError;
+use actix_web::http::StatusCode;
+#[cfg(feature = "serde")]
+use actix_web::web::Query;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use crate::{ULIDError, ULID};
+
+/// The rejection of [ULIDPath] and [ULIDQuery].
+#[derive(Debug, Error)]
+pub enum ULIDRejection {
+  /// The parameter is not a valid [ULID].
+  #[error(transparent)]
+  InvalidULID(#[from] ULIDError),
+  /// The route does not have exactly one path parameter.
+  #[error("expected 1 path parameter, got {0}")]
+  Path(usize),
+  /// The query string could not be deserialized, e.g. because of an invalid [ULID].
+  #[cfg(feature = "serde")]
+  #[error(transparent)]
+  Query(#[from] QueryPayloadError),
+}
+
+impl ULIDRejection {
+  /// The machine-readable `error` of the response.
+  pub fn code(&self) -> &'static str {
+    match self {
+      ULIDRejection::InvalidULID(_) => "invalid_ulid",
+      ULIDRejection::Path(_) => "invalid_path",
+      #[cfg(feature = "serde")]
+      ULIDRejection::Query(_) => "invalid_query",
+    }
+  }
+}
+
+impl ResponseError for ULIDRejection {
+  fn status_code(&self) -> StatusCode {
+    match self {
+      ULIDRejection::Path(_) => StatusCode::INTERNAL_SERVER_ERROR,
+      _ => StatusCode::BAD_REQUEST,
+    }
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    HttpResponse::build(self.status_code()).json(serde_json::json!({
+      "error": self.code(),
+      "message": self.to_string(),
+    }))
+  }
+}
+
+/// Extracts a [ULID] from the single path parameter of a route.
+#[derive(Debug, Copy, Clone, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ULIDPath(pub ULID);
+
+impl From<ULIDPath> for ULID {
+  fn from(value: ULIDPath) -> Self {
+    value.0
+  }
+}
+
+impl FromRequest for ULIDPath {
+  type Error = ULIDRejection;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+    let params = req.match_info();
+    let result = match params.iter().map(|(_, value)| value).collect::<Vec<_>>()[..] {
+      [value] => value.parse().map(ULIDPath).map_err(ULIDRejection::from),
+      ref values => Err(ULIDRejection::Path(values.len())),
+    };
+    ready(result)
+  }
+}
+
+/// Deserializes the query string into `T`, rejecting with a [ULIDRejection].
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ULIDQuery<T>(pub T);
+
+#[cfg(feature = "serde")]
+impl<T> FromRequest for ULIDQuery<T>
+where
+  T: DeserializeOwned,
+{
+  type Error = ULIDRejection;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+    let result = Query::<T>::from_query(req.query_string())
+      .map(|query| ULIDQuery(query.into_inner()))
+      .map_err(ULIDRejection::from);
+    ready(result)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_STR};
+  use actix_web::body::to_bytes;
+  use actix_web::test::TestRequest;
+
+  async fn response_body(rejection: ULIDRejection) -> (StatusCode, String) {
+    let response = rejection.error_response();
+    let status = response.status();
+    let body = to_bytes(response.into_body()).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+  }
+
+  #[actix_web::test]
+  async fn path() {
+    let req = TestRequest::default().param("id", GOLDEN_STR).to_http_request();
+    let ULIDPath(id) = ULIDPath::extract(&req).await.unwrap();
+    assert_eq!(id, GOLDEN);
+
+    let req = TestRequest::default()
+      .param("id", "01ETGRM6448X1HM0PYWG2KT64U")
+      .to_http_request();
+    let rejection = ULIDPath::extract(&req).await.unwrap_err();
+    assert_eq!(
+      response_body(rejection).await,
+      (
+        StatusCode::BAD_REQUEST,
+        r#"{"error":"invalid_ulid","message":"invalid the char: U"}"#.to_string()
+      )
+    );
+
+    let req = TestRequest::default().to_http_request();
+    let rejection = ULIDPath::extract(&req).await.unwrap_err();
+    assert_eq!(rejection.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+  }
+
+  #[cfg(feature = "serde")]
+  #[actix_web::test]
+  async fn query() {
+    #[derive(Debug, serde::Deserialize)]
+    struct Params {
+      after: ULID,
+    }
+
+    let req = TestRequest::with_uri(&format!("/users?after={}", GOLDEN_STR)).to_http_request();
+    let ULIDQuery(params) = ULIDQuery::<Params>::extract(&req).await.unwrap();
+    assert_eq!(params.after, GOLDEN);
+
+    let req = TestRequest::with_uri("/users?after=01ETGRM6448X1HM0PYWG2KT64U").to_http_request();
+    let rejection = ULIDQuery::<Params>::extract(&req).await.unwrap_err();
+    let (status, body) = response_body(rejection).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["error"], "invalid_query");
+    assert!(body["message"].as_str().unwrap().contains("invalid the char: U"));
+  }
+}
@@ -0,0 +1,59 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [arbitrary](https://github.com/rust-fuzz/arbitrary) support for fuzzing.
+//!
+//! Arbitrary [ULID]s favor edge values: [ULID::MIN], [ULID::MAX], IDs whose randomness is all ones
+//! just before [ULID::increment] overflows, and IDs with the largest timestamp `2^48-1`.
+
+use arbitrary::{size_hint, Arbitrary, Result, Unstructured};
+
+use crate::ULID;
+
+impl<'a> Arbitrary<'a> for ULID {
+  fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+    Ok(ULID::arbitrary_from(
+      u8::arbitrary(u)?,
+      u64::arbitrary(u)?,
+      u128::arbitrary(u)?,
+    ))
+  }
+
+  fn size_hint(depth: usize) -> (usize, Option<usize>) {
+    size_hint::and_all(&[
+      <u8 as Arbitrary>::size_hint(depth),
+      <u64 as Arbitrary>::size_hint(depth),
+      <u128 as Arbitrary>::size_hint(depth),
+    ])
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{MAX_TIMESTAMP, RANDOMNESS_MASK};
+
+  #[test]
+  fn edge_values() {
+    let edge = |selector: u8| {
+      let mut bytes = vec![selector];
+      bytes.extend_from_slice(&[0x5a; 24]);
+      ULID::arbitrary(&mut Unstructured::new(&bytes)).unwrap()
+    };
+    assert_eq!(edge(0), ULID::MIN);
+    assert_eq!(edge(1), ULID::MAX);
+    assert_eq!(edge(2).0 & RANDOMNESS_MASK, RANDOMNESS_MASK);
+    assert_eq!(edge(3).to_epoch_milli_as_long(), MAX_TIMESTAMP as i64);
+  }
+
+  #[test]
+  fn exhausted_input() {
+    let ulid = ULID::arbitrary(&mut Unstructured::new(&[])).unwrap();
+    assert_eq!(ulid, ULID::MIN);
+  }
+}
@@ -0,0 +1,81 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [async-graphql](https://github.com/async-graphql/async-graphql) support.
+//!
+//! A [ULID] is exposed as the `ULID` scalar, represented by its 26-character canonical string.
+
+use async_graphql::{InputValueError, InputValueResult, Scalar, ScalarType, Value};
+
+use crate::ULID;
+
+/// The `ULID` scalar.
+#[Scalar(name = "ULID", specified_by_url = "https://github.com/ulid/spec")]
+impl ScalarType for ULID {
+  fn parse(value: Value) -> InputValueResult<Self> {
+    match &value {
+      Value::String(s) => Ok(s.parse::<ULID>()?),
+      _ => Err(InputValueError::expected_type(value)),
+    }
+  }
+
+  fn is_valid(value: &Value) -> bool {
+    matches!(value, Value::String(_))
+  }
+
+  fn to_value(&self) -> Value {
+    Value::String(self.to_string())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::GOLDEN_STR;
+  use async_graphql::{EmptyMutation, EmptySubscription, Object, Schema};
+
+  struct Query;
+
+  #[Object]
+  impl Query {
+    async fn echo(&self, id: ULID) -> ULID {
+      id
+    }
+  }
+
+  async fn execute(query: &str) -> async_graphql::Response {
+    let schema = Schema::new(Query, EmptyMutation, EmptySubscription);
+    schema.execute(query).await
+  }
+
+  #[tokio::test]
+  async fn valid_id() {
+    let response = execute(&format!(r#"{{ echo(id: "{}") }}"#, GOLDEN_STR)).await;
+    assert!(response.errors.is_empty(), "{:?}", response.errors);
+    assert_eq!(
+      response.data.into_json().unwrap(),
+      serde_json::json!({ "echo": GOLDEN_STR })
+    );
+  }
+
+  #[tokio::test]
+  async fn invalid_ids() {
+    for (id, message) in [
+      ("01ETGRM6448X1HM0PYWG2KT64", "invalid length"),
+      ("01ETGRM6448X1HM0PYWG2KT64U", "invalid the char: U"),
+      ("81ETGRM6448X1HM0PYWG2KT648", "data type overflow"),
+    ] {
+      let response = execute(&format!(r#"{{ echo(id: "{}") }}"#, id)).await;
+      assert_eq!(response.errors.len(), 1);
+      assert_eq!(
+        response.errors[0].message,
+        format!(r#"Failed to parse "ULID": {}"#, message)
+      );
+    }
+  }
+}
@@ -0,0 +1,188 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [axum](https://github.com/tokio-rs/axum) support.
+//!
+//! [ULIDPath] extracts a [ULID] from the single path parameter of a route, and [ULIDQuery] (with the `serde`
+//! feature) deserializes query parameters containing [ULID]s. Both reject with a [ULIDRejection], a JSON
+//! response such as `{"error":"invalid_ulid","message":"invalid the char: U"}`.
+//!
+//! ```rust
+//! use axum::routing::get;
+//! use axum::Router;
+//! use ulid_generator_rs::axum::ULIDPath;
+//!
+//! async fn show(ULIDPath(id): ULIDPath) -> String {
+//!   id.to_string()
+//! }
+//!
+//! let app: Router = Router::new().route("/users/{id}", get(show));
+//! ```
+
+use axum::extract::rejection::PathRejection;
+#[cfg(feature = "serde")]
+use axum::extract::rejection::QueryRejection;
+#[cfg(feature = "serde")]
+use axum::extract::Query;
+use axum::extract::{FromRequestParts, Path};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+use thiserror::Error;
+
+use crate::{ULIDError, ULID};
+
+/// The rejection of [ULIDPath] and [ULIDQuery].
+#[derive(Debug, Error)]
+pub enum ULIDRejection {
+  /// The parameter is not a valid [ULID].
+  #[error(transparent)]
+  InvalidULID(#[from] ULIDError),
+  /// The path parameter could not be extracted.
+  #[error(transparent)]
+  Path(#[from] PathRejection),
+  /// The query string could not be deserialized, e.g. because of an invalid [ULID].
+  #[cfg(feature = "serde")]
+  #[error(transparent)]
+  Query(#[from] QueryRejection),
+}
+
+impl ULIDRejection {
+  /// The HTTP status of the response.
+  pub fn status(&self) -> StatusCode {
+    match self {
+      ULIDRejection::InvalidULID(_) => StatusCode::BAD_REQUEST,
+      ULIDRejection::Path(rejection) => rejection.status(),
+      #[cfg(feature = "serde")]
+      ULIDRejection::Query(rejection) => rejection.status(),
+    }
+  }
+
+  /// The machine-readable `error` of the response.
+  pub fn code(&self) -> &'static str {
+    match self {
+      ULIDRejection::InvalidULID(_) => "invalid_ulid",
+      ULIDRejection::Path(_) => "invalid_path",
+      #[cfg(feature = "serde")]
+      ULIDRejection::Query(_) => "invalid_query",
+    }
+  }
+}
+
+impl IntoResponse for ULIDRejection {
+  fn into_response(self) -> Response {
+    let body = serde_json::json!({
+      "error": self.code(),
+      "message": self.to_string(),
+    });
+    (self.status(), Json(body)).into_response()
+  }
+}
+
+/// Extracts a [ULID] from the single path parameter of a route.
+#[derive(Debug, Copy, Clone, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ULIDPath(pub ULID);
+
+impl From<ULIDPath> for ULID {
+  fn from(value: ULIDPath) -> Self {
+    value.0
+  }
+}
+
+impl<S> FromRequestParts<S> for ULIDPath
+where
+  S: Send + Sync,
+{
+  type Rejection = ULIDRejection;
+
+  async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+    let Path(value) = Path::<String>::from_request_parts(parts, state).await?;
+    Ok(ULIDPath(value.parse()?))
+  }
+}
+
+/// Deserializes the query string into `T`, rejecting with a [ULIDRejection].
+#[cfg(feature = "serde")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ULIDQuery<T>(pub T);
+
+#[cfg(feature = "serde")]
+impl<T, S> FromRequestParts<S> for ULIDQuery<T>
+where
+  T: DeserializeOwned,
+  S: Send + Sync,
+{
+  type Rejection = ULIDRejection;
+
+  async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+    let Query(value) = Query::<T>::from_request_parts(parts, state).await?;
+    Ok(ULIDQuery(value))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN_EPOCH_MILLI, GOLDEN_STR};
+  use axum::body::{to_bytes, Body};
+  use axum::http::Request;
+  use axum::routing::get;
+  use axum::Router;
+  use tower::ServiceExt;
+
+  async fn request(app: Router, uri: &str) -> (StatusCode, String) {
+    let response = app
+      .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+      .await
+      .unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+  }
+
+  #[tokio::test]
+  async fn path() {
+    let app = Router::new().route(
+      "/users/{id}",
+      get(|ULIDPath(id): ULIDPath| async move { id.to_epoch_milli_as_long().to_string() }),
+    );
+    let (status, body) = request(app.clone(), &format!("/users/{}", GOLDEN_STR)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, GOLDEN_EPOCH_MILLI.to_string());
+
+    let (status, body) = request(app, "/users/01ETGRM6448X1HM0PYWG2KT64U").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body, r#"{"error":"invalid_ulid","message":"invalid the char: U"}"#);
+  }
+
+  #[cfg(feature = "serde")]
+  #[tokio::test]
+  async fn query() {
+    #[derive(serde::Deserialize)]
+    struct Params {
+      after: ULID,
+    }
+
+    let app = Router::new().route(
+      "/users",
+      get(|ULIDQuery(params): ULIDQuery<Params>| async move { params.after.to_string() }),
+    );
+    let (status, body) = request(app.clone(), &format!("/users?after={}", GOLDEN_STR)).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, GOLDEN_STR);
+
+    let (status, body) = request(app, "/users?after=01ETGRM6448X1HM0PYWG2KT64U").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let body: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(body["error"], "invalid_query");
+    assert!(body["message"].as_str().unwrap().contains("invalid the char: U"));
+  }
+}
@@ -0,0 +1,63 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The decoded components of a ULID, shared by `ulid inspect` and `ulid-server`.
+
+use chrono::{SecondsFormat, TimeZone, Utc};
+use serde::Serialize;
+use ulid_generator_rs::{Endian, ULID};
+use uuid::Uuid;
+
+/// The decoded components of a [ULID].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Components {
+  pub ulid: String,
+  pub timestamp_utc: String,
+  pub epoch_millis: i64,
+  pub randomness: String,
+  pub uuid: String,
+  pub bytes: String,
+}
+
+impl Components {
+  pub fn new(ulid: ULID) -> Self {
+    let epoch_millis = ulid.to_epoch_milli_as_long();
+    let bytes: Vec<String> = ulid
+      .to_byte_array(Endian::BE)
+      .iter()
+      .map(|b| format!("{:02x}", b))
+      .collect();
+    Self {
+      ulid: ulid.to_string(),
+      timestamp_utc: Utc
+        .timestamp_millis_opt(epoch_millis)
+        .unwrap()
+        .to_rfc3339_opts(SecondsFormat::Millis, true),
+      epoch_millis,
+      randomness: format!("{:020x}", ulid.randomness()),
+      uuid: Uuid::from(ulid).hyphenated().to_string(),
+      bytes: bytes.join(" "),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn components() {
+    let components = Components::new("01ETGRM6448X1HM0PYWG2KT648".parse().unwrap());
+    assert_eq!(components.ulid, "01ETGRM6448X1HM0PYWG2KT648");
+    assert_eq!(components.timestamp_utc, "2020-12-27T00:12:30.724Z");
+    assert_eq!(components.epoch_millis, 1_609_027_950_724);
+    assert_eq!(components.randomness, "47431a02dee4053d1888");
+    assert_eq!(components.uuid, "0176a18a-1884-4743-1a02-dee4053d1888");
+    assert_eq!(components.bytes, "01 76 a1 8a 18 84 47 43 1a 02 de e4 05 3d 18 88");
+  }
+}
@@ -0,0 +1,250 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `ulid-server` HTTP/JSON ID allocation service.
+//!
+//! ```sh
+//! $ ulid-server 127.0.0.1:7070
+//! $ curl 'http://127.0.0.1:7070/ulid?count=3'
+//! {"ulids":["01ETGRM6448X1HM0PYWG2KT648","01ETGRM6448X1HM0PYWG2KT649","01ETGRM6448X1HM0PYWG2KT64A"]}
+//! $ curl 'http://127.0.0.1:7070/inspect/01ETGRM6448X1HM0PYWG2KT648'
+//! ```
+//!
+//! - `GET /ulid?count=N&monotonic=true` returns `N` (default 1, at most 10000) new ULIDs. With `monotonic`,
+//!   the default, every ULID is strictly greater than any ULID returned before by this process.
+//! - `GET /inspect/{id}` returns the components of a ULID.
+
+#[path = "../common/components.rs"]
+mod components;
+
+use std::net::SocketAddr;
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex};
+
+use axum::extract::rejection::QueryRejection;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use tokio::net::TcpListener;
+use ulid_generator_rs::{ULIDGenerator, ULID};
+
+use crate::components::Components;
+
+const DEFAULT_ADDR: &str = "127.0.0.1:7070";
+const MAX_COUNT: usize = 10_000;
+
+/// The generator shared by all clients, with the last [ULID] handed out.
+type SharedGenerator = Arc<Mutex<(ULIDGenerator, Option<ULID>)>>;
+
+#[derive(Debug, Deserialize)]
+struct GenerateParams {
+  #[serde(default = "default_count")]
+  count: usize,
+  #[serde(default = "default_monotonic")]
+  monotonic: bool,
+}
+
+fn default_count() -> usize {
+  1
+}
+
+fn default_monotonic() -> bool {
+  true
+}
+
+#[derive(Debug, Serialize)]
+struct GenerateResponse {
+  ulids: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+  error: String,
+}
+
+fn error(status: StatusCode, message: impl ToString) -> Response {
+  let body = ErrorResponse {
+    error: message.to_string(),
+  };
+  (status, Json(body)).into_response()
+}
+
+async fn generate(
+  State(shared): State<SharedGenerator>,
+  params: Result<Query<GenerateParams>, QueryRejection>,
+) -> Response {
+  let Query(params) = match params {
+    Ok(params) => params,
+    Err(rejection) => return error(rejection.status(), rejection.body_text()),
+  };
+  if params.count == 0 || params.count > MAX_COUNT {
+    return error(
+      StatusCode::BAD_REQUEST,
+      format!("count must be between 1 and {}", MAX_COUNT),
+    );
+  }
+  let mut guard = shared.lock().unwrap();
+  let (generator, last_id) = &mut *guard;
+  let mut ulids = Vec::with_capacity(params.count);
+  for _ in 0..params.count {
+    let ulid = match *last_id {
+      Some(last_id) if params.monotonic => generator.generate_next(&last_id),
+      _ => generator.generate(),
+    };
+    match ulid {
+      Ok(ulid) => {
+        if last_id.is_none_or(|last_id| last_id < ulid) {
+          *last_id = Some(ulid);
+        }
+        ulids.push(ulid.to_string());
+      }
+      Err(e) => return error(StatusCode::SERVICE_UNAVAILABLE, e),
+    }
+  }
+  Json(GenerateResponse { ulids }).into_response()
+}
+
+async fn inspect(Path(id): Path<String>) -> Response {
+  let ulid = match id.parse::<ULID>() {
+    Ok(ulid) => ulid,
+    Err(e) => return error(StatusCode::BAD_REQUEST, e),
+  };
+  Json(Components::new(ulid)).into_response()
+}
+
+fn app() -> Router {
+  let generator = ULIDGenerator::new();
+  let shared: SharedGenerator = Arc::new(Mutex::new((generator, None)));
+  Router::new()
+    .route("/ulid", get(generate))
+    .route("/inspect/{id}", get(inspect))
+    .with_state(shared)
+}
+
+async fn serve(listener: TcpListener) -> std::io::Result<()> {
+  axum::serve(listener, app())
+    .with_graceful_shutdown(async {
+      let _ = tokio::signal::ctrl_c().await;
+    })
+    .await
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+  let addr = std::env::args().nth(1).unwrap_or_else(|| DEFAULT_ADDR.to_string());
+  let addr: SocketAddr = match addr.parse() {
+    Ok(addr) => addr,
+    Err(e) => {
+      eprintln!("ulid-server: invalid address {}: {}", addr, e);
+      return ExitCode::FAILURE;
+    }
+  };
+  let listener = match TcpListener::bind(addr).await {
+    Ok(listener) => listener,
+    Err(e) => {
+      eprintln!("ulid-server: failed to bind {}: {}", addr, e);
+      return ExitCode::FAILURE;
+    }
+  };
+  eprintln!("ulid-server: listening on {}", addr);
+  match serve(listener).await {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(e) => {
+      eprintln!("ulid-server: {}", e);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tokio::io::{AsyncReadExt, AsyncWriteExt};
+  use tokio::net::TcpStream;
+
+  async fn start() -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(serve(listener));
+    addr
+  }
+
+  async fn get(addr: SocketAddr, path: &str) -> (u16, serde_json::Value) {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    let request = format!("GET {} HTTP/1.1\r\nHost: localhost\r\nConnection: close\r\n\r\n", path);
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    let status = response[9..12].parse().unwrap();
+    let (_, body) = response.split_once("\r\n\r\n").unwrap();
+    (status, serde_json::from_str(body).unwrap())
+  }
+
+  fn ulids(body: &serde_json::Value) -> Vec<ULID> {
+    body["ulids"]
+      .as_array()
+      .unwrap()
+      .iter()
+      .map(|ulid| ulid.as_str().unwrap().parse().unwrap())
+      .collect()
+  }
+
+  #[tokio::test]
+  async fn strictly_increasing_across_clients() {
+    let addr = start().await;
+    let requests = (0..8).map(|_| tokio::spawn(async move { get(addr, "/ulid?count=50").await }));
+    let mut all = Vec::new();
+    for request in requests {
+      let (status, body) = request.await.unwrap();
+      assert_eq!(status, 200);
+      let ulids = ulids(&body);
+      assert_eq!(ulids.len(), 50);
+      assert!(ulids.windows(2).all(|w| w[0] < w[1]));
+      all.extend(ulids);
+    }
+    let count = all.len();
+    all.sort();
+    all.dedup();
+    assert_eq!(all.len(), count);
+
+    let (status, body) = get(addr, "/ulid?monotonic=false").await;
+    assert_eq!(status, 200);
+    assert_eq!(ulids(&body).len(), 1);
+    let (status, body) = get(addr, "/ulid?count=0").await;
+    assert_eq!(status, 400);
+    assert!(body["error"].is_string());
+  }
+
+  #[tokio::test]
+  async fn malformed_query() {
+    let addr = start().await;
+    for path in ["/ulid?count=abc", "/ulid?monotonic=yes"] {
+      let (status, body) = get(addr, path).await;
+      assert_eq!(status, 400);
+      let error = body["error"].as_str().unwrap();
+      assert!(error.starts_with("Failed to deserialize query string"), "{}", error);
+    }
+  }
+
+  #[tokio::test]
+  async fn inspect() {
+    let addr = start().await;
+    let (status, body) = get(addr, "/inspect/01ETGRM6448X1HM0PYWG2KT648").await;
+    assert_eq!(status, 200);
+    assert_eq!(body["timestamp_utc"], "2020-12-27T00:12:30.724Z");
+    assert_eq!(body["epoch_millis"], 1_609_027_950_724i64);
+    assert_eq!(body["uuid"], "0176a18a-1884-4743-1a02-dee4053d1888");
+
+    let (status, body) = get(addr, "/inspect/01ETGRM6448X1HM0PYWG2KT64U").await;
+    assert_eq!(status, 400);
+    assert_eq!(body["error"], "invalid the char: U");
+  }
+}
@@ -0,0 +1,139 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `ulid convert` subcommand.
+
+use std::io::{self, BufRead, BufWriter, Write};
+
+use clap::Args;
+
+use crate::Format;
+
+#[derive(Debug, Args)]
+pub struct ConvertArgs {
+  /// The input format.
+  #[arg(long, value_enum)]
+  from: Format,
+  /// The output format.
+  #[arg(long, value_enum, default_value_t = Format::Canonical)]
+  to: Format,
+  /// Exits successfully despite invalid lines instead of exiting with an error at the end.
+  #[arg(long)]
+  skip_invalid: bool,
+}
+
+/// Converts `input` line by line into `out`, reporting invalid lines to `err`.
+///
+/// Returns the number of invalid lines. Every input line produces one output line, so the output stays
+/// aligned with the input: blank and invalid lines, including lines that are not UTF-8, produce blank lines.
+fn convert<R, W, E>(args: &ConvertArgs, input: R, out: &mut W, err: &mut E) -> io::Result<usize>
+where
+  R: BufRead,
+  W: Write,
+  E: Write, {
+  let mut errors = 0;
+  for (index, line) in crate::read_lines(input).enumerate() {
+    let line_number = index + 1;
+    let line = match line? {
+      Ok(line) => line,
+      Err(line) => {
+        writeln!(err, "ulid: line {}: invalid UTF-8: {}", line_number, line.trim())?;
+        writeln!(out)?;
+        errors += 1;
+        continue;
+      }
+    };
+    let value = line.trim();
+    if value.is_empty() {
+      writeln!(out)?;
+      continue;
+    }
+    match args.from.parse(value) {
+      Ok(ulid) => writeln!(out, "{}", args.to.format(ulid))?,
+      Err(e) => {
+        writeln!(err, "ulid: line {}: {}: {}", line_number, e, value)?;
+        writeln!(out)?;
+        errors += 1;
+      }
+    }
+  }
+  out.flush()?;
+  Ok(errors)
+}
+
+pub fn run(args: &ConvertArgs) -> Result<(), Box<dyn std::error::Error>> {
+  let mut out = BufWriter::new(io::stdout().lock());
+  let errors = convert(args, io::stdin().lock(), &mut out, &mut io::stderr().lock())?;
+  if errors > 0 && !args.skip_invalid {
+    return Err(format!("{} invalid line(s)", errors).into());
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use clap::Parser;
+
+  fn convert_str(command: &[&str], input: &[u8]) -> (String, String, usize) {
+    let cli = crate::Cli::parse_from(command);
+    let args = match cli.command {
+      Some(crate::Command::Convert(args)) => args,
+      _ => unreachable!(),
+    };
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    let errors = convert(&args, input, &mut out, &mut err).unwrap();
+    (String::from_utf8(out).unwrap(), String::from_utf8(err).unwrap(), errors)
+  }
+
+  #[test]
+  fn uuid_to_canonical() {
+    let (out, err, errors) = convert_str(
+      &["ulid", "convert", "--from", "uuid"],
+      b"0176a18a-1884-4743-1a02-dee4053d1888\n\n0176A18A188447431A02DEE4053D1888\r\n",
+    );
+    assert_eq!(out, "01ETGRM6448X1HM0PYWG2KT648\n\n01ETGRM6448X1HM0PYWG2KT648\n");
+    assert_eq!((err.as_str(), errors), ("", 0));
+  }
+
+  #[test]
+  fn invalid_lines() {
+    let (out, err, errors) = convert_str(
+      &[
+        "ulid",
+        "convert",
+        "--from",
+        "canonical",
+        "--to",
+        "hex",
+        "--skip-invalid",
+      ],
+      b"01ETGRM6448X1HM0PYWG2KT648\nnot-a-ulid\n\n01etgrm6448x1hm0pywg2kt648",
+    );
+    assert_eq!(
+      out,
+      "0176a18a188447431a02dee4053d1888\n\n\n0176a18a188447431a02dee4053d1888\n"
+    );
+    assert_eq!(err, "ulid: line 2: invalid length: not-a-ulid\n");
+    assert_eq!(errors, 1);
+  }
+
+  #[test]
+  fn non_utf8_lines() {
+    let (out, err, errors) = convert_str(
+      &["ulid", "convert", "--from", "u128"],
+      b"1945195434112195390215103893317949576\n\xff1\n+1\n1945195434112195390215103893317949576\n",
+    );
+    assert_eq!(out, "01ETGRM6448X1HM0PYWG2KT648\n\n\n01ETGRM6448X1HM0PYWG2KT648\n");
+    assert_eq!(
+      err,
+      "ulid: line 2: invalid UTF-8: \u{fffd}1\nulid: line 3: invalid u128, expected decimal digits: +1\n"
+    );
+    assert_eq!(errors, 2);
+  }
+}
@@ -0,0 +1,211 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `ulid inspect` subcommand.
+
+use std::io::{self, BufWriter, Write};
+
+use chrono::{SecondsFormat, Utc};
+use clap::Args;
+use serde::Serialize;
+use ulid_generator_rs::ULID;
+
+use crate::components::Components;
+
+#[derive(Debug, Args)]
+pub struct InspectArgs {
+  /// The ULIDs to inspect. Reads stdin, one per line, when omitted.
+  ids: Vec<String>,
+  /// Writes one JSON object per line.
+  #[arg(long)]
+  json: bool,
+}
+
+/// The inspection of an input [ULID].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct Inspection {
+  input: String,
+  #[serde(flatten)]
+  components: Components,
+  timestamp_local: String,
+  age_millis: i64,
+  non_canonical: bool,
+  out_of_order: bool,
+}
+
+impl Inspection {
+  fn new(input: &str, ulid: ULID, previous_id: Option<ULID>, now_epoch_milli: i64) -> Self {
+    let components = Components::new(ulid);
+    Self {
+      input: input.to_string(),
+      timestamp_local: ulid.to_date_time().to_rfc3339_opts(SecondsFormat::Millis, false),
+      age_millis: now_epoch_milli - components.epoch_millis,
+      non_canonical: input != components.ulid,
+      out_of_order: previous_id.is_some_and(|previous_id| ulid <= previous_id),
+      components,
+    }
+  }
+
+  fn write_text<W: Write>(&self, out: &mut W) -> io::Result<()> {
+    let components = &self.components;
+    writeln!(out, "{}", components.ulid)?;
+    writeln!(out, "  timestamp (UTC)    {}", components.timestamp_utc)?;
+    writeln!(out, "  timestamp (local)  {}", self.timestamp_local)?;
+    writeln!(out, "  epoch millis       {}", components.epoch_millis)?;
+    writeln!(out, "  randomness         {}", components.randomness)?;
+    writeln!(out, "  uuid               {}", components.uuid)?;
+    writeln!(out, "  bytes              {}", components.bytes)?;
+    writeln!(out, "  age                {}", format_age(self.age_millis))?;
+    if self.non_canonical {
+      writeln!(out, "  warning            non-canonical input {}", self.input)?;
+    }
+    if self.out_of_order {
+      writeln!(
+        out,
+        "  warning            out of order, not greater than the previous ULID"
+      )?;
+    }
+    Ok(())
+  }
+}
+
+/// Formats an age in milli seconds, e.g. `1d 2h 3m 4.567s ago`.
+fn format_age(age_millis: i64) -> String {
+  let millis = age_millis.unsigned_abs();
+  let (days, hours, minutes) = (millis / 86_400_000, millis / 3_600_000 % 24, millis / 60_000 % 60);
+  let seconds = format!("{}.{:03}s", millis / 1000 % 60, millis % 1000);
+  let age = if days > 0 {
+    format!("{}d {}h {}m {}", days, hours, minutes, seconds)
+  } else if hours > 0 {
+    format!("{}h {}m {}", hours, minutes, seconds)
+  } else if minutes > 0 {
+    format!("{}m {}", minutes, seconds)
+  } else {
+    seconds
+  };
+  if age_millis < 0 {
+    format!("in {}", age)
+  } else {
+    format!("{} ago", age)
+  }
+}
+
+/// Inspects `inputs` into `out`, reporting invalid inputs to `err`.
+///
+/// Returns the number of invalid inputs. Blank inputs are skipped.
+fn inspect<I, W, E>(
+  args: &InspectArgs,
+  inputs: I,
+  now_epoch_milli: i64,
+  out: &mut W,
+  err: &mut E,
+) -> Result<usize, Box<dyn std::error::Error>>
+where
+  I: Iterator<Item = io::Result<Result<String, String>>>,
+  W: Write,
+  E: Write, {
+  let mut previous_id = None;
+  let mut errors = 0;
+  for (index, input) in inputs.enumerate() {
+    let input = match input? {
+      Ok(input) => input,
+      Err(input) => {
+        writeln!(err, "ulid: line {}: invalid UTF-8: {}", index + 1, input.trim())?;
+        errors += 1;
+        continue;
+      }
+    };
+    let input = input.trim();
+    if input.is_empty() {
+      continue;
+    }
+    match input.parse::<ULID>() {
+      Ok(ulid) => {
+        let inspection = Inspection::new(input, ulid, previous_id, now_epoch_milli);
+        if args.json {
+          serde_json::to_writer(&mut *out, &inspection)?;
+          writeln!(out)?;
+        } else {
+          inspection.write_text(out)?;
+        }
+        previous_id = Some(ulid);
+      }
+      Err(e) => {
+        writeln!(err, "ulid: line {}: {}: {}", index + 1, e, input)?;
+        errors += 1;
+      }
+    }
+  }
+  out.flush()?;
+  Ok(errors)
+}
+
+pub fn run(args: &InspectArgs) -> Result<(), Box<dyn std::error::Error>> {
+  let inputs: Box<dyn Iterator<Item = io::Result<Result<String, String>>>> = if args.ids.is_empty() {
+    Box::new(crate::read_lines(io::stdin().lock()))
+  } else {
+    Box::new(args.ids.iter().cloned().map(|id| Ok(Ok(id))))
+  };
+  let mut out = BufWriter::new(io::stdout().lock());
+  let now_epoch_milli = Utc::now().timestamp_millis();
+  let errors = inspect(args, inputs, now_epoch_milli, &mut out, &mut io::stderr().lock())?;
+  if errors > 0 {
+    return Err(format!("{} invalid input(s)", errors).into());
+  }
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn inspection() {
+    let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+    let inspection = Inspection::new("01ETGRM6448X1HM0PYWG2KT648", ulid, None, 1_609_027_950_724 + 90_061_001);
+    assert_eq!(inspection.components, Components::new(ulid));
+    assert_eq!(format_age(inspection.age_millis), "1d 1h 1m 1.001s ago");
+    assert!(!inspection.non_canonical);
+    assert!(!inspection.out_of_order);
+  }
+
+  #[test]
+  fn warnings() {
+    let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+    let inspection = Inspection::new(
+      "01etgrm6448x1hm0pywg2kt648",
+      ulid,
+      Some(ulid.increment()),
+      1_609_027_889_724,
+    );
+    assert!(inspection.non_canonical);
+    assert!(inspection.out_of_order);
+    assert_eq!(format_age(inspection.age_millis), "in 1m 1.000s");
+    let duplicate = Inspection::new("01ETGRM6448X1HM0PYWG2KT648", ulid, Some(ulid), 1_609_027_950_724);
+    assert!(duplicate.out_of_order);
+  }
+
+  #[test]
+  fn non_utf8_stdin() {
+    let args = InspectArgs {
+      ids: Vec::new(),
+      json: true,
+    };
+    let input: &[u8] = b"01ETGRM6448X1HM0PYWG2KT648\n\xff\xfe\n01ETGRM6448X1HM0PYWG2KT649\r\n";
+    let (mut out, mut err) = (Vec::new(), Vec::new());
+    let errors = inspect(&args, crate::read_lines(input), 0, &mut out, &mut err).unwrap();
+    assert_eq!(errors, 1);
+    assert_eq!(
+      String::from_utf8(err).unwrap(),
+      "ulid: line 2: invalid UTF-8: \u{fffd}\u{fffd}\n"
+    );
+    let out = String::from_utf8(out).unwrap();
+    assert_eq!(out.lines().count(), 2);
+    assert!(out.lines().all(|line| line.contains(r#""out_of_order":false"#)));
+  }
+}
@@ -0,0 +1,268 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `ulid` command line tool.
+//!
+//! ```sh
+//! $ ulid -n 3 --monotonic --format lowercase
+//! $ ulid inspect 01ETGRM6448X1HM0PYWG2KT648
+//! $ ulid convert --from uuid --to canonical < uuids.txt
+//! ```
+
+#[path = "../common/components.rs"]
+mod components;
+mod convert;
+mod inspect;
+
+use std::io::{self, BufRead, BufWriter, Write};
+use std::process::ExitCode;
+
+use chrono::DateTime;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use ulid_generator_rs::{Clock, ThreadLocalRng, ULIDGenerator, ULID};
+use uuid::Uuid;
+
+/// Generates ULIDs and writes them to stdout, one per line.
+#[derive(Debug, Parser)]
+#[command(name = "ulid", version, args_conflicts_with_subcommands = true)]
+struct Cli {
+  #[command(subcommand)]
+  command: Option<Command>,
+  #[command(flatten)]
+  generate: GenerateArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+  /// Decodes ULIDs given as arguments or on stdin, one per line.
+  Inspect(inspect::InspectArgs),
+  /// Converts ULIDs on stdin from one format to another, one per line.
+  Convert(convert::ConvertArgs),
+}
+
+#[derive(Debug, Args)]
+struct GenerateArgs {
+  /// The number of ULIDs to generate.
+  #[arg(short = 'n', value_name = "COUNT", default_value_t = 1)]
+  count: usize,
+  /// Increments the previous ULID instead of drawing new random bits within the same millisecond.
+  #[arg(long)]
+  monotonic: bool,
+  /// Generates at the given time instead of now.
+  #[arg(long, value_name = "RFC3339|MILLIS", value_parser = parse_epoch_milli)]
+  at: Option<u64>,
+  /// The output format.
+  #[arg(long, value_enum, default_value_t = Format::Canonical)]
+  format: Format,
+}
+
+/// The text formats of a [ULID].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum Format {
+  /// `01ETGRM6448X1HM0PYWG2KT648`
+  Canonical,
+  /// `01etgrm6448x1hm0pywg2kt648`
+  Lowercase,
+  /// `0176a18a-1884-4743-1a02-dee4053d1888`
+  Uuid,
+  /// `0176a18a188447431a02dee4053d1888`
+  Hex,
+  /// `1945195434112195390215103893317949576`
+  U128,
+}
+
+impl Format {
+  fn format(self, ulid: ULID) -> String {
+    match self {
+      Format::Canonical => ulid.to_string(),
+      Format::Lowercase => ulid.to_string().to_ascii_lowercase(),
+      Format::Uuid => Uuid::from(ulid).hyphenated().to_string(),
+      Format::Hex => format!("{:032x}", u128::from(ulid)),
+      Format::U128 => u128::from(ulid).to_string(),
+    }
+  }
+
+  /// Parses `input` in this format. `Canonical` and `Lowercase` both accept either case.
+  fn parse(self, input: &str) -> Result<ULID, String> {
+    match self {
+      Format::Canonical | Format::Lowercase => input.parse::<ULID>().map_err(|e| e.to_string()),
+      Format::Uuid => Uuid::parse_str(input).map(ULID::from).map_err(|e| e.to_string()),
+      Format::Hex => {
+        let hex = input.strip_prefix("0x").unwrap_or(input);
+        if hex.is_empty() || hex.len() > 32 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+          return Err("invalid hex, expected up to 32 hex digits".to_string());
+        }
+        u128::from_str_radix(hex, 16).map(ULID::from).map_err(|e| e.to_string())
+      }
+      Format::U128 => {
+        if input.is_empty() || !input.bytes().all(|b| b.is_ascii_digit()) {
+          return Err("invalid u128, expected decimal digits".to_string());
+        }
+        input.parse::<u128>().map(ULID::from).map_err(|e| e.to_string())
+      }
+    }
+  }
+}
+
+/// Parses `value` as epoch milli seconds or as an RFC 3339 date and time.
+fn parse_epoch_milli(value: &str) -> Result<u64, String> {
+  let epoch_milli = if !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit()) {
+    value.parse::<u64>().map_err(|e| e.to_string())?
+  } else {
+    let date_time =
+      DateTime::parse_from_rfc3339(value).map_err(|e| format!("{}, expected RFC 3339 or epoch milli seconds", e))?;
+    u64::try_from(date_time.timestamp_millis()).map_err(|_| "must not be before the Unix epoch".to_string())?
+  };
+  if epoch_milli >= 1 << 48 {
+    return Err("must be less than 2^48 milli seconds".to_string());
+  }
+  Ok(epoch_milli)
+}
+
+/// Returns the lines of `input` without their line endings.
+///
+/// Lines are read as bytes, so that a line that is not UTF-8 can be reported like any other invalid
+/// line instead of failing the whole read like [`BufRead::lines`]. Such a line is yielded as `Err`
+/// holding its lossily decoded text.
+fn read_lines<R: BufRead>(mut input: R) -> impl Iterator<Item = io::Result<Result<String, String>>> {
+  let mut buf = Vec::new();
+  std::iter::from_fn(move || {
+    buf.clear();
+    match input.read_until(b'\n', &mut buf) {
+      Ok(0) => None,
+      Ok(_) => {
+        let line = buf.strip_suffix(b"\n").unwrap_or(&buf);
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let line = std::str::from_utf8(line)
+          .map(str::to_string)
+          .map_err(|_| String::from_utf8_lossy(line).into_owned());
+        Some(Ok(line))
+      }
+      Err(e) => Some(Err(e)),
+    }
+  })
+}
+
+fn write_ulids<C, W>(
+  generator: &mut ULIDGenerator<ThreadLocalRng, C>,
+  args: &GenerateArgs,
+  out: &mut W,
+) -> Result<(), Box<dyn std::error::Error>>
+where
+  C: Clock,
+  W: Write, {
+  let mut previous_id: Option<ULID> = None;
+  for _ in 0..args.count {
+    let ulid = match (args.monotonic, previous_id) {
+      (true, Some(previous_id)) => generator.generate_monotonic(&previous_id)?,
+      _ => generator.generate()?,
+    };
+    writeln!(out, "{}", args.format.format(ulid))?;
+    previous_id = Some(ulid);
+  }
+  out.flush()?;
+  Ok(())
+}
+
+fn generate(args: &GenerateArgs) -> Result<(), Box<dyn std::error::Error>> {
+  let mut out = BufWriter::new(io::stdout().lock());
+  match args.at {
+    Some(at) => write_ulids(
+      &mut ULIDGenerator::with_rng_and_clock(ThreadLocalRng, move || at),
+      args,
+      &mut out,
+    ),
+    None => write_ulids(&mut ULIDGenerator::new(), args, &mut out),
+  }
+}
+
+fn run(cli: &Cli) -> Result<(), Box<dyn std::error::Error>> {
+  match &cli.command {
+    Some(Command::Inspect(args)) => inspect::run(args),
+    Some(Command::Convert(args)) => convert::run(args),
+    None => generate(&cli.generate),
+  }
+}
+
+fn main() -> ExitCode {
+  let cli = Cli::parse();
+  match run(&cli) {
+    Ok(()) => ExitCode::SUCCESS,
+    Err(e) if e.downcast_ref::<io::Error>().map(io::Error::kind) == Some(io::ErrorKind::BrokenPipe) => {
+      ExitCode::SUCCESS
+    }
+    Err(e) => {
+      eprintln!("ulid: {}", e);
+      ExitCode::FAILURE
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn format() {
+    let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+    assert_eq!(Format::Canonical.format(ulid), "01ETGRM6448X1HM0PYWG2KT648");
+    assert_eq!(Format::Lowercase.format(ulid), "01etgrm6448x1hm0pywg2kt648");
+    assert_eq!(Format::Uuid.format(ulid), "0176a18a-1884-4743-1a02-dee4053d1888");
+    assert_eq!(Format::Hex.format(ulid), "0176a18a188447431a02dee4053d1888");
+    assert_eq!(Format::U128.format(ulid), u128::from(ulid).to_string());
+  }
+
+  #[test]
+  fn parse() {
+    let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+    for format in [
+      Format::Canonical,
+      Format::Lowercase,
+      Format::Uuid,
+      Format::Hex,
+      Format::U128,
+    ] {
+      assert_eq!(format.parse(&format.format(ulid)), Ok(ulid));
+    }
+    assert_eq!(Format::Uuid.parse("0176a18a188447431a02dee4053d1888"), Ok(ulid));
+    assert_eq!(Format::Hex.parse("0x0176a18a188447431a02dee4053d1888"), Ok(ulid));
+    assert!(Format::Hex.parse("+1").is_err());
+    assert!(Format::Hex.parse("0176a18a188447431a02dee4053d18880").is_err());
+    assert_eq!(
+      Format::U128.parse("+1"),
+      Err("invalid u128, expected decimal digits".to_string())
+    );
+    assert!(Format::U128.parse("340282366920938463463374607431768211456").is_err());
+  }
+
+  #[test]
+  fn at() {
+    assert_eq!(parse_epoch_milli("1609459200000"), Ok(1_609_459_200_000));
+    assert_eq!(parse_epoch_milli("2021-01-01T09:00:00+09:00"), Ok(1_609_459_200_000));
+    assert!(parse_epoch_milli("1969-12-31T23:59:59Z").is_err());
+    assert!(parse_epoch_milli("281474976710656").is_err());
+    assert!(parse_epoch_milli("yesterday").is_err());
+  }
+
+  #[test]
+  fn monotonic_at() {
+    let cli = Cli::parse_from(["ulid", "-n", "3", "--monotonic", "--at", "1609459200000"]);
+    let mut generator = ULIDGenerator::with_rng_and_clock(ThreadLocalRng, || 1_609_459_200_000);
+    let mut out = Vec::new();
+    write_ulids(&mut generator, &cli.generate, &mut out).unwrap();
+    let ulids: Vec<ULID> = String::from_utf8(out)
+      .unwrap()
+      .lines()
+      .map(|line| line.parse().unwrap())
+      .collect();
+    assert_eq!(ulids.len(), 3);
+    assert_eq!(ulids[1], ulids[0].increment());
+    assert_eq!(ulids[2], ulids[1].increment());
+    assert_eq!(ulids[0].to_epoch_milli_as_long(), 1_609_459_200_000);
+  }
+}
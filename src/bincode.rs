@@ -0,0 +1,55 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [bincode](https://github.com/bincode-org/bincode) 2 native `Encode`/`Decode` support.
+//!
+//! A [ULID] is encoded as exactly 16 big-endian bytes, the same layout as [`ULID::to_byte_array`] with
+//! [`Endian::BE`](crate::Endian::BE), regardless of the bincode configuration.
+
+use bincode::de::Decoder;
+use bincode::enc::Encoder;
+use bincode::error::{DecodeError, EncodeError};
+use bincode::{Decode, Encode};
+
+use crate::{ULID, ULID_BYTES_LENGTH};
+
+impl Encode for ULID {
+  fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+    self.0.to_be_bytes().encode(encoder)
+  }
+}
+
+impl<Context> Decode<Context> for ULID {
+  fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+    let bytes = <[u8; ULID_BYTES_LENGTH as usize]>::decode(decoder)?;
+    Ok(ULID::from(u128::from_be_bytes(bytes)))
+  }
+}
+
+bincode::impl_borrow_decode!(ULID);
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_BYTES};
+  use bincode::config;
+
+  #[test]
+  fn golden_bytes() {
+    for bytes in [
+      bincode::encode_to_vec(GOLDEN, config::standard()).unwrap(),
+      bincode::encode_to_vec(GOLDEN, config::legacy()).unwrap(),
+    ] {
+      assert_eq!(bytes, GOLDEN_BYTES);
+    }
+    let (decoded, read): (ULID, usize) = bincode::decode_from_slice(&GOLDEN_BYTES, config::standard()).unwrap();
+    assert_eq!(decoded, GOLDEN);
+    assert_eq!(read, 16);
+    assert!(bincode::decode_from_slice::<ULID, _>(&GOLDEN_BYTES[..15], config::standard()).is_err());
+  }
+}
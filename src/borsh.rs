@@ -0,0 +1,48 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [borsh](https://github.com/near/borsh-rs) support.
+//!
+//! A [ULID] is encoded as exactly 16 big-endian bytes, the same layout as [`ULID::to_byte_array`] with
+//! [`Endian::BE`](crate::Endian::BE).
+
+use std::io::{Read, Result, Write};
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::{ULID, ULID_BYTES_LENGTH};
+
+impl BorshSerialize for ULID {
+  fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+    writer.write_all(&self.0.to_be_bytes())
+  }
+}
+
+impl BorshDeserialize for ULID {
+  fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+    let mut bytes = [0; ULID_BYTES_LENGTH as usize];
+    reader.read_exact(&mut bytes)?;
+    Ok(ULID::from(u128::from_be_bytes(bytes)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_BYTES};
+  use crate::Endian;
+
+  #[test]
+  fn golden_bytes() {
+    let bytes = borsh::to_vec(&GOLDEN).unwrap();
+    assert_eq!(bytes, GOLDEN_BYTES);
+    assert_eq!(bytes, GOLDEN.to_byte_array(Endian::BE));
+    assert_eq!(borsh::from_slice::<ULID>(&bytes).unwrap(), GOLDEN);
+    assert!(borsh::from_slice::<ULID>(&bytes[..15]).is_err());
+  }
+}
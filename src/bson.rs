@@ -0,0 +1,208 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [bson](https://github.com/mongodb/bson-rust) support.
+//!
+//! A [ULID] converts to a BSON [Binary] of subtype UUID (`0x04`) by default, or of the user-defined subtype
+//! `0x80` through [ULIDAsUserDefined]. Converting back accepts both subtypes.
+//!
+//! [`ULID::from_object_id`] and [`ULID::to_object_id`] map between [ULID]s and MongoDB [ObjectId]s while
+//! preserving their sort order, to migrate existing collections.
+
+use std::convert::TryFrom;
+
+use bson::oid::ObjectId;
+use bson::spec::BinarySubtype;
+use bson::{Binary, Bson};
+
+use crate::{ULIDError, RANDOMNESS_MASK, ULID, ULID_BYTES_LENGTH};
+
+/// The user-defined BSON binary subtype of [ULIDAsUserDefined].
+pub const USER_DEFINED_SUBTYPE: u8 = 0x80;
+
+/// A [ULID] converted to a BSON [Binary] of the user-defined subtype `0x80`.
+#[derive(Debug, Copy, Clone, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ULIDAsUserDefined(pub ULID);
+
+impl From<ULID> for ULIDAsUserDefined {
+  fn from(ulid: ULID) -> Self {
+    Self(ulid)
+  }
+}
+
+impl From<ULIDAsUserDefined> for ULID {
+  fn from(value: ULIDAsUserDefined) -> Self {
+    value.0
+  }
+}
+
+fn to_binary(ulid: ULID, subtype: BinarySubtype) -> Binary {
+  Binary {
+    subtype,
+    bytes: u128::from(ulid).to_be_bytes().to_vec(),
+  }
+}
+
+impl From<ULID> for Binary {
+  fn from(ulid: ULID) -> Self {
+    to_binary(ulid, BinarySubtype::Uuid)
+  }
+}
+
+impl From<ULID> for Bson {
+  fn from(ulid: ULID) -> Self {
+    Bson::Binary(ulid.into())
+  }
+}
+
+impl From<ULIDAsUserDefined> for Binary {
+  fn from(value: ULIDAsUserDefined) -> Self {
+    to_binary(value.0, BinarySubtype::UserDefined(USER_DEFINED_SUBTYPE))
+  }
+}
+
+impl From<ULIDAsUserDefined> for Bson {
+  fn from(value: ULIDAsUserDefined) -> Self {
+    Bson::Binary(value.into())
+  }
+}
+
+impl TryFrom<&Binary> for ULID {
+  type Error = ULIDError;
+
+  /// Converts a [Binary] of subtype UUID or `0x80` holding 16 bytes.
+  fn try_from(binary: &Binary) -> Result<Self, Self::Error> {
+    match binary.subtype {
+      BinarySubtype::Uuid | BinarySubtype::UserDefined(USER_DEFINED_SUBTYPE) => {
+        let bytes = <[u8; ULID_BYTES_LENGTH as usize]>::try_from(binary.bytes.as_slice())
+          .map_err(|_| ULIDError::InvalidByteArrayError)?;
+        Ok(ULID::from(u128::from_be_bytes(bytes)))
+      }
+      _ => Err(ULIDError::InvalidByteArrayError),
+    }
+  }
+}
+
+impl TryFrom<Binary> for ULID {
+  type Error = ULIDError;
+
+  fn try_from(binary: Binary) -> Result<Self, Self::Error> {
+    ULID::try_from(&binary)
+  }
+}
+
+impl TryFrom<Bson> for ULID {
+  type Error = ULIDError;
+
+  /// Converts a [`Bson::Binary`] like [`TryFrom<Binary>`], or a [`Bson::String`] holding a ULID string.
+  fn try_from(bson: Bson) -> Result<Self, Self::Error> {
+    match bson {
+      Bson::Binary(binary) => ULID::try_from(&binary),
+      Bson::String(text) => text.parse(),
+      _ => Err(ULIDError::InvalidByteArrayError),
+    }
+  }
+}
+
+impl ULID {
+  /// Converts an [ObjectId] to a [ULID].
+  ///
+  /// The timestamp of the [ULID] is the seconds of the [ObjectId] in milli seconds, and its randomness is the
+  /// remaining 8 bytes of the [ObjectId], so [ULID]s sort like the [ObjectId]s they came from.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use bson::oid::ObjectId;
+  /// use ulid_generator_rs::ULID;
+  ///
+  /// let object_id = ObjectId::parse_str("5fe7d16e0123456789abcdef").unwrap();
+  /// let ulid = ULID::from_object_id(object_id);
+  /// assert_eq!(ulid.to_epoch_milli_as_long(), 1_609_027_950_000);
+  /// assert_eq!(ulid.to_object_id(), Ok(object_id));
+  /// ```
+  pub fn from_object_id(object_id: ObjectId) -> Self {
+    let bytes = object_id.bytes();
+    let seconds = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    let mut randomness = [0; 8];
+    randomness.copy_from_slice(&bytes[4..]);
+    ULID::from_timestamp_and_randomness(u64::from(seconds) * 1000, u64::from_be_bytes(randomness) as u128)
+  }
+
+  /// Converts a [ULID] to an [ObjectId], the inverse of [`ULID::from_object_id`].
+  ///
+  /// This is lossy: the milli seconds and the upper 16 bits of the randomness are dropped. Returns an error
+  /// if the timestamp is beyond the seconds an [ObjectId] can hold, i.e. after the year 2106.
+  pub fn to_object_id(&self) -> Result<ObjectId, ULIDError> {
+    let seconds = u32::try_from(self.to_epoch_milli_as_long() / 1000).map_err(|_| ULIDError::TimestampOverflowError)?;
+    let randomness = (self.0 & RANDOMNESS_MASK) as u64;
+    let mut bytes = [0; 12];
+    bytes[..4].copy_from_slice(&seconds.to_be_bytes());
+    bytes[4..].copy_from_slice(&randomness.to_be_bytes());
+    Ok(ObjectId::from_bytes(bytes))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_BYTES, GOLDEN_EPOCH_MILLI, GOLDEN_STR};
+
+  #[test]
+  fn binary() {
+    let uuid = Binary::from(GOLDEN);
+    assert_eq!(uuid.subtype, BinarySubtype::Uuid);
+    assert_eq!(uuid.bytes, GOLDEN_BYTES);
+    let user_defined = Binary::from(ULIDAsUserDefined(GOLDEN));
+    assert_eq!(user_defined.subtype, BinarySubtype::UserDefined(0x80));
+    assert_eq!(user_defined.bytes, uuid.bytes);
+
+    assert_eq!(ULID::try_from(&uuid), Ok(GOLDEN));
+    assert_eq!(ULID::try_from(user_defined), Ok(GOLDEN));
+    assert_eq!(ULID::try_from(Bson::from(GOLDEN)), Ok(GOLDEN));
+    assert_eq!(ULID::try_from(Bson::from(ULIDAsUserDefined(GOLDEN))), Ok(GOLDEN));
+    assert_eq!(ULID::try_from(Bson::String(GOLDEN_STR.to_string())), Ok(GOLDEN));
+  }
+
+  #[test]
+  fn binary_error() {
+    let generic = Binary {
+      subtype: BinarySubtype::Generic,
+      bytes: vec![0; 16],
+    };
+    assert_eq!(ULID::try_from(generic), Err(ULIDError::InvalidByteArrayError));
+    let short = Binary {
+      subtype: BinarySubtype::Uuid,
+      bytes: vec![0; 15],
+    };
+    assert_eq!(ULID::try_from(short), Err(ULIDError::InvalidByteArrayError));
+    assert_eq!(ULID::try_from(Bson::Int32(1)), Err(ULIDError::InvalidByteArrayError));
+  }
+
+  #[test]
+  fn object_id() {
+    let object_ids = [
+      ObjectId::parse_str("5fe7d16e0000000000000001").unwrap(),
+      ObjectId::parse_str("5fe7d16e0123456789abcdef").unwrap(),
+      ObjectId::parse_str("5fe7d16effffffffffffffff").unwrap(),
+      ObjectId::parse_str("5fe7d16f0000000000000000").unwrap(),
+    ];
+    let ulids: Vec<ULID> = object_ids.iter().copied().map(ULID::from_object_id).collect();
+    assert!(ulids.windows(2).all(|w| w[0] < w[1]));
+    for (object_id, ulid) in object_ids.iter().zip(&ulids) {
+      assert_eq!(ulid.to_object_id(), Ok(*object_id));
+    }
+
+    let object_id = GOLDEN.to_object_id().unwrap();
+    assert_eq!(
+      object_id.timestamp().timestamp_millis(),
+      GOLDEN_EPOCH_MILLI as i64 / 1000 * 1000
+    );
+    assert_eq!(ULID::MAX.to_object_id(), Err(ULIDError::TimestampOverflowError));
+  }
+}
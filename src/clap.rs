@@ -0,0 +1,196 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [clap](https://github.com/clap-rs/clap) support.
+//!
+//! [ULIDValueParser] parses [ULID] arguments, and is the default value parser of a [ULID] argument. Besides
+//! ULID strings, it accepts relative times such as `now`, `now-1h` or `now+30s`, which resolve to the smallest
+//! [ULID] of that instant. Units are `ms`, `s`, `m`, `h` and `d`.
+//!
+//! ```rust
+//! use clap::Parser;
+//! use ulid_generator_rs::clap::ULIDValueParser;
+//! use ulid_generator_rs::ULID;
+//!
+//! #[derive(Parser)]
+//! struct Args {
+//!   /// Accepts lowercase and Crockford aliases.
+//!   #[arg(long)]
+//!   after: ULID,
+//!   /// Accepts the canonical form only.
+//!   #[arg(long, value_parser = ULIDValueParser::new().canonical(true))]
+//!   id: ULID,
+//! }
+//!
+//! let args = Args::parse_from(["app", "--after", "now-1h", "--id", "01ETGRM6448X1HM0PYWG2KT648"]);
+//! assert_eq!(args.id.to_string(), "01ETGRM6448X1HM0PYWG2KT648");
+//! assert!(args.after > args.id);
+//! ```
+
+use std::ffi::OsStr;
+
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::{Arg, Command, Error};
+
+use crate::{Clock, SystemClock, ULIDError, ENCODING_DIGITS, TIMESTAMP_OVERFLOW_MASK, ULID};
+
+const RELATIVE_TIME_ERROR: &str =
+  "invalid relative time, expected now, now-<N><unit> or now+<N><unit> with unit ms, s, m, h or d";
+
+/// The [TypedValueParser] of [ULID] arguments.
+///
+/// By default, parsing is lenient like [`ULID::from_str`](core::str::FromStr), accepting lowercase letters and
+/// the Crockford aliases `I`, `L` and `O`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct ULIDValueParser {
+  canonical: bool,
+}
+
+impl ULIDValueParser {
+  /// The Constructor for a lenient [ULIDValueParser].
+  #[must_use]
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Accepts canonical, uppercase ULID strings only.
+  #[must_use]
+  pub fn canonical(mut self, canonical: bool) -> Self {
+    self.canonical = canonical;
+    self
+  }
+
+  fn parse_with_clock<C: Clock>(self, value: &str, clock: &C) -> Result<ULID, String> {
+    if let Some(offset) = value.strip_prefix("now") {
+      let now = clock.now_epoch_milli().map_err(|e| e.to_string())?;
+      let epoch_milli = resolve_relative_time(now, offset)?;
+      if (epoch_milli & TIMESTAMP_OVERFLOW_MASK) != 0 {
+        return Err(ULIDError::TimestampOverflowError.to_string());
+      }
+      return Ok(ULID::from_timestamp_and_randomness(epoch_milli, 0));
+    }
+    let ulid = value.parse::<ULID>().map_err(|e| e.to_string())?;
+    if self.canonical {
+      if let Some(c) = value.chars().find(|c| !ENCODING_DIGITS.contains(&(*c as u8))) {
+        return Err(ULIDError::InvalidChar(c).to_string());
+      }
+    }
+    Ok(ulid)
+  }
+}
+
+/// Applies an `offset` such as `-1h` or `+30s` to `now`.
+fn resolve_relative_time(now: u64, offset: &str) -> Result<u64, String> {
+  let Some(sign) = offset.chars().next() else {
+    return Ok(now);
+  };
+  let offset = &offset[sign.len_utf8()..];
+  let split = offset.find(|c: char| !c.is_ascii_digit()).unwrap_or(offset.len());
+  let (amount, unit) = offset.split_at(split);
+  let amount = amount.parse::<u64>().map_err(|_| RELATIVE_TIME_ERROR.to_string())?;
+  let unit_millis = match unit {
+    "ms" => 1,
+    "s" => 1_000,
+    "m" => 60_000,
+    "h" => 3_600_000,
+    "d" => 86_400_000,
+    _ => return Err(RELATIVE_TIME_ERROR.to_string()),
+  };
+  let overflow = || ULIDError::TimestampOverflowError.to_string();
+  let millis = amount.checked_mul(unit_millis).ok_or_else(overflow)?;
+  match sign {
+    '-' => now
+      .checked_sub(millis)
+      .ok_or_else(|| ULIDError::TimestampUnderflowError.to_string()),
+    '+' => now.checked_add(millis).ok_or_else(overflow),
+    _ => Err(RELATIVE_TIME_ERROR.to_string()),
+  }
+}
+
+impl TypedValueParser for ULIDValueParser {
+  type Value = ULID;
+
+  fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr) -> Result<Self::Value, Error> {
+    let parser = *self;
+    let parse = move |value: &str| parser.parse_with_clock(value, &SystemClock);
+    parse.parse_ref(cmd, arg, value)
+  }
+}
+
+impl ValueParserFactory for ULID {
+  type Parser = ULIDValueParser;
+
+  fn value_parser() -> Self::Parser {
+    ULIDValueParser::new()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_EPOCH_MILLI as NOW, GOLDEN_STR};
+  use clap::error::ErrorKind;
+
+  fn parse(parser: ULIDValueParser, value: &str) -> Result<ULID, String> {
+    parser.parse_with_clock(value, &|| NOW)
+  }
+
+  #[test]
+  fn lenient_and_canonical() {
+    let lowercase = GOLDEN_STR.to_ascii_lowercase();
+    let canonical = ULIDValueParser::new().canonical(true);
+    assert_eq!(parse(ULIDValueParser::new(), &lowercase), Ok(GOLDEN));
+    assert_eq!(parse(ULIDValueParser::new(), "O1ETGRM6448X1HMOPYWG2KT648"), Ok(GOLDEN));
+    assert_eq!(parse(canonical, GOLDEN_STR), Ok(GOLDEN));
+    assert_eq!(parse(canonical, &lowercase), Err("invalid the char: e".to_string()));
+    assert_eq!(
+      parse(canonical, "O1ETGRM6448X1HM0PYWG2KT648"),
+      Err("invalid the char: O".to_string())
+    );
+    assert_eq!(parse(canonical, "01ETGRM644"), Err("invalid length".to_string()));
+  }
+
+  #[test]
+  fn relative_time() {
+    let parser = ULIDValueParser::new();
+    assert_eq!(parse(parser, "now"), Ok(ULID::from_timestamp_and_randomness(NOW, 0)));
+    assert_eq!(
+      parse(parser, "now-1h"),
+      Ok(ULID::from_timestamp_and_randomness(NOW - 3_600_000, 0))
+    );
+    assert_eq!(
+      parse(parser, "now+30s"),
+      Ok(ULID::from_timestamp_and_randomness(NOW + 30_000, 0))
+    );
+    assert_eq!(
+      parse(parser, "now-1d").unwrap().to_epoch_milli_as_long(),
+      (NOW - 86_400_000) as i64
+    );
+    assert_eq!(
+      parse(parser, "now-100000d"),
+      Err("timestamp must not be before the Unix epoch".to_string())
+    );
+    assert_eq!(parse(parser, "now-1y"), Err(RELATIVE_TIME_ERROR.to_string()));
+    assert_eq!(parse(parser, "now-h"), Err(RELATIVE_TIME_ERROR.to_string()));
+    assert_eq!(parse(parser, "now*1h"), Err(RELATIVE_TIME_ERROR.to_string()));
+  }
+
+  #[test]
+  fn error_output() {
+    let cmd = Command::new("app").arg(
+      Arg::new("id")
+        .long("id")
+        .value_parser(ULIDValueParser::new().canonical(true)),
+    );
+    let error = cmd
+      .try_get_matches_from(["app", "--id", "01ETGRM6448X1HM0PYWG2KT64U"])
+      .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::ValueValidation);
+    assert!(error.to_string().contains("invalid the char: U"));
+  }
+}
@@ -0,0 +1,255 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [Diesel](https://diesel.rs) support.
+//!
+//! Enable the `diesel-postgres`, `diesel-sqlite` or `diesel-mysql` feature for the target backend.
+//!
+//! A [ULID] can be stored in `Binary` columns as its 16 big-endian bytes, in `Text` columns as
+//! its canonical string, and in PostgreSQL `Uuid` columns. The [`sql_types::ULID`] SQL type picks
+//! the native 16-byte representation of each backend and can be used in `table!` macros.
+//!
+//! # Examples
+//!
+//! ```
+//! # use diesel::prelude::*;
+//! diesel::table! {
+//!   use ulid_generator_rs::diesel::sql_types::ULID;
+//!
+//!   items (id) {
+//!     id -> ULID,
+//!   }
+//! }
+//! ```
+
+use diesel::backend::Backend;
+use diesel::deserialize::{self, FromSql, FromSqlRow};
+use diesel::expression::AsExpression;
+use diesel::sql_types::{Binary, Text};
+
+use crate::{Endian, ULID};
+
+/// SQL types for [ULID].
+pub mod sql_types {
+  use diesel::query_builder::QueryId;
+  use diesel::sql_types::SqlType;
+
+  /// The SQL type of a [ULID](crate::ULID).
+  ///
+  /// It maps to `uuid` on PostgreSQL, `BLOB` on SQLite and `BINARY(16)` on MySQL.
+  #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+  #[diesel(postgres_type(oid = 2950, array_oid = 2951))]
+  #[diesel(sqlite_type(name = "Binary"))]
+  #[diesel(mysql_type(name = "Blob"))]
+  pub struct ULID;
+}
+
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = sql_types::ULID)]
+#[diesel(sql_type = Binary)]
+#[diesel(sql_type = Text)]
+#[cfg_attr(feature = "diesel-postgres", diesel(sql_type = diesel::sql_types::Uuid))]
+struct ULIDProxy(ULID);
+
+impl<DB> FromSql<Binary, DB> for ULID
+where
+  DB: Backend,
+  Vec<u8>: FromSql<Binary, DB>,
+{
+  fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+    let byte_array = Vec::<u8>::from_sql(bytes)?;
+    Ok(ULID::parse_from_byte_array(byte_array, Endian::BE)?)
+  }
+}
+
+impl<DB> FromSql<Text, DB> for ULID
+where
+  DB: Backend,
+  String: FromSql<Text, DB>,
+{
+  fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+    let text = String::from_sql(bytes)?;
+    Ok(text.parse::<ULID>()?)
+  }
+}
+
+#[cfg(feature = "diesel-postgres")]
+mod pg {
+  use super::*;
+  use diesel::pg::{Pg, PgValue};
+  use diesel::serialize::{self, IsNull, Output, ToSql};
+  use diesel::sql_types::Uuid;
+  use std::io::Write;
+
+  impl ToSql<Binary, Pg> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+      out.write_all(&self.to_byte_array(Endian::BE))?;
+      Ok(IsNull::No)
+    }
+  }
+
+  impl ToSql<Text, Pg> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+      out.write_all(self.to_string().as_bytes())?;
+      Ok(IsNull::No)
+    }
+  }
+
+  impl ToSql<Uuid, Pg> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+      ToSql::<Binary, Pg>::to_sql(self, out)
+    }
+  }
+
+  impl FromSql<Uuid, Pg> for ULID {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+      Ok(ULID::parse_from_byte_array(value.as_bytes().to_vec(), Endian::BE)?)
+    }
+  }
+
+  impl ToSql<sql_types::ULID, Pg> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+      ToSql::<Uuid, Pg>::to_sql(self, out)
+    }
+  }
+
+  impl FromSql<sql_types::ULID, Pg> for ULID {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+      FromSql::<Uuid, Pg>::from_sql(value)
+    }
+  }
+}
+
+#[cfg(feature = "diesel-sqlite")]
+mod sqlite {
+  use super::*;
+  use diesel::serialize::{self, IsNull, Output, ToSql};
+  use diesel::sqlite::{Sqlite, SqliteValue};
+
+  impl ToSql<Binary, Sqlite> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+      out.set_value(self.to_byte_array(Endian::BE));
+      Ok(IsNull::No)
+    }
+  }
+
+  impl ToSql<Text, Sqlite> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+      out.set_value(self.to_string());
+      Ok(IsNull::No)
+    }
+  }
+
+  impl ToSql<sql_types::ULID, Sqlite> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+      ToSql::<Binary, Sqlite>::to_sql(self, out)
+    }
+  }
+
+  impl FromSql<sql_types::ULID, Sqlite> for ULID {
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+      FromSql::<Binary, Sqlite>::from_sql(value)
+    }
+  }
+}
+
+#[cfg(feature = "diesel-mysql")]
+mod mysql {
+  use super::*;
+  use diesel::mysql::{Mysql, MysqlValue};
+  use diesel::serialize::{self, IsNull, Output, ToSql};
+  use std::io::Write;
+
+  impl ToSql<Binary, Mysql> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+      out.write_all(&self.to_byte_array(Endian::BE))?;
+      Ok(IsNull::No)
+    }
+  }
+
+  impl ToSql<Text, Mysql> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+      out.write_all(self.to_string().as_bytes())?;
+      Ok(IsNull::No)
+    }
+  }
+
+  impl ToSql<sql_types::ULID, Mysql> for ULID {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+      ToSql::<Binary, Mysql>::to_sql(self, out)
+    }
+  }
+
+  impl FromSql<sql_types::ULID, Mysql> for ULID {
+    fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+      FromSql::<Binary, Mysql>::from_sql(value)
+    }
+  }
+}
+
+#[cfg(all(test, feature = "diesel-sqlite"))]
+mod tests {
+  use super::*;
+  use crate::fixtures::ulids;
+  use diesel::prelude::*;
+  use diesel::sqlite::SqliteConnection;
+
+  diesel::table! {
+    use crate::diesel::sql_types::ULID;
+    use diesel::sql_types::{Binary, Text};
+
+    items (id) {
+      id -> ULID,
+      binary_id -> Binary,
+      text_id -> Text,
+    }
+  }
+
+  fn setup() -> QueryResult<(SqliteConnection, Vec<ULID>)> {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query(
+      "CREATE TABLE items (id BLOB PRIMARY KEY NOT NULL, binary_id BLOB NOT NULL, text_id TEXT NOT NULL)",
+    )
+    .execute(&mut conn)?;
+    let ulids = ulids();
+    for ulid in &ulids {
+      diesel::insert_into(items::table)
+        .values((items::id.eq(ulid), items::binary_id.eq(ulid), items::text_id.eq(ulid)))
+        .execute(&mut conn)?;
+    }
+    Ok((conn, ulids))
+  }
+
+  #[test]
+  fn ulid_sql_type() -> QueryResult<()> {
+    let (mut conn, mut expected) = setup()?;
+    let actual: Vec<ULID> = items::table.select(items::id).order(items::id).load(&mut conn)?;
+    expected.sort();
+    assert_eq!(actual, expected);
+    Ok(())
+  }
+
+  #[test]
+  fn binary_and_text() -> QueryResult<()> {
+    let (mut conn, mut expected) = setup()?;
+    expected.sort();
+    let actual: Vec<(ULID, ULID)> = items::table
+      .select((items::binary_id, items::text_id))
+      .order(items::text_id)
+      .load(&mut conn)?;
+    assert_eq!(actual.iter().map(|(b, _)| *b).collect::<Vec<_>>(), expected);
+    assert_eq!(actual.iter().map(|(_, t)| *t).collect::<Vec<_>>(), expected);
+    let stored: String = items::table
+      .select(items::text_id)
+      .filter(items::id.eq(expected[0]))
+      .first(&mut conn)?;
+    assert_eq!(stored, expected[0].to_string());
+    Ok(())
+  }
+}
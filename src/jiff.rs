@@ -0,0 +1,56 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [jiff](https://github.com/BurntSushi/jiff) support.
+//!
+//! A `jiff::Timestamp` can also be passed to [`ULIDGenerator::generate_at`](crate::ULIDGenerator::generate_at).
+
+use jiff::Timestamp;
+
+use crate::ULID;
+
+impl ULID {
+  /// Converts a [ULID] to a [Timestamp].
+  ///
+  /// Returns an error if the timestamp is beyond the range supported by [Timestamp], i.e. after the year 9999.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use jiff::Timestamp;
+  /// use ulid_generator_rs::{ULIDGenerator, ULID};
+  ///
+  /// let mut generator: ULIDGenerator = ULIDGenerator::new();
+  /// let ulid: ULID = generator.generate().unwrap();
+  /// let timestamp: Timestamp = ulid.to_timestamp().unwrap();
+  /// ```
+  pub fn to_timestamp(&self) -> Result<Timestamp, jiff::Error> {
+    Timestamp::from_millisecond(self.to_epoch_milli_as_long())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::fixtures::GOLDEN;
+  use crate::{ULIDGenerator, ULID};
+  use jiff::Timestamp;
+
+  #[test]
+  fn to_timestamp() {
+    let expected: Timestamp = "2020-12-27T00:12:30.724Z".parse().unwrap();
+    assert_eq!(GOLDEN.to_timestamp().unwrap(), expected);
+    assert!(ULID::MAX.to_timestamp().is_err());
+  }
+
+  #[test]
+  fn generate_at() {
+    let timestamp: Timestamp = "2021-01-01T00:00:00Z".parse().unwrap();
+    let ulid = ULIDGenerator::new().generate_at(timestamp).unwrap();
+    assert_eq!(ulid.to_timestamp().unwrap(), timestamp);
+  }
+}
@@ -0,0 +1,103 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [Juniper](https://github.com/graphql-rust/juniper) support.
+//!
+//! A [ULID] is exposed as the `ULID` scalar, represented by its 26-character canonical string.
+//!
+//! Values passed as variables are rejected with the [ULIDError](crate::ULIDError) message, e.g.
+//! ``Failed to parse `ULID`: invalid length``. Juniper validates inline literals without keeping the
+//! scalar's error, so an invalid literal such as `echo(id: "01ETGRM6448X1HM0PYWG2KT64")` is only
+//! reported as `Invalid value "..." for type "ULID"`.
+
+use juniper::{graphql_scalar, InputValue, ScalarValue, Value};
+
+use crate::ULID;
+
+/// The `ULID` scalar.
+#[graphql_scalar(
+  name = "ULID",
+  with = ulid_scalar,
+  parse_token(String),
+  specified_by_url = "https://github.com/ulid/spec",
+)]
+type ULIDScalar = ULID;
+
+mod ulid_scalar {
+  use super::*;
+  use crate::ULIDError;
+
+  pub(super) fn to_output<S: ScalarValue>(v: &ULID) -> Value<S> {
+    Value::scalar(v.to_string())
+  }
+
+  pub(super) fn from_input<S: ScalarValue>(v: &InputValue<S>) -> Result<ULID, String> {
+    v.as_string_value()
+      .ok_or_else(|| format!("Expected `String`, found: {}", v))
+      .and_then(|s| {
+        s.parse::<ULID>()
+          .map_err(|e: ULIDError| format!("Failed to parse `ULID`: {}", e))
+      })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::GOLDEN_STR;
+  use juniper::{graphql_object, EmptyMutation, EmptySubscription, RootNode, Variables};
+
+  struct Query;
+
+  #[graphql_object]
+  impl Query {
+    fn echo(id: ULID) -> ULID {
+      id
+    }
+  }
+
+  fn execute(query: &str, variables: &Variables) -> Result<Value, String> {
+    let schema = RootNode::new(Query, EmptyMutation::<()>::new(), EmptySubscription::<()>::new());
+    match juniper::execute_sync(query, None, &schema, variables, &()) {
+      Ok((value, errors)) if errors.is_empty() => Ok(value),
+      Ok((_, errors)) => Err(format!("{:?}", errors)),
+      Err(error) => Err(error.to_string()),
+    }
+  }
+
+  #[test]
+  fn valid_id() {
+    let value = execute(&format!(r#"{{ echo(id: "{}") }}"#, GOLDEN_STR), &Variables::new()).unwrap();
+    assert_eq!(value, juniper::graphql_value!({ "echo": GOLDEN_STR }));
+  }
+
+  #[test]
+  fn invalid_ids() {
+    for (id, message) in [
+      ("01ETGRM6448X1HM0PYWG2KT64", "invalid length"),
+      ("01ETGRM6448X1HM0PYWG2KT64U", "invalid the char: U"),
+      ("81ETGRM6448X1HM0PYWG2KT648", "data type overflow"),
+    ] {
+      // Juniper drops the scalar's error for inline literals and reports a generic message.
+      let error = execute(&format!(r#"{{ echo(id: "{}") }}"#, id), &Variables::new()).unwrap_err();
+      assert!(
+        error.contains(&format!(r#"Invalid value ""{}"" for type "ULID""#, id)),
+        "{}",
+        error
+      );
+
+      let variables = Variables::from([("id".to_string(), InputValue::scalar(id))]);
+      let error = execute("query($id: ULID!) { echo(id: $id) }", &variables).unwrap_err();
+      assert!(
+        error.contains(&format!("Failed to parse `ULID`: {}", message)),
+        "{}",
+        error
+      );
+    }
+  }
+}
@@ -24,32 +24,178 @@
 //! println!("{}", str); // "01ETGRM6448X1HM0PYWG2KT648"
 //! ```
 //!
+//! # `no_std`
+//!
+//! Disable the default features to build for targets without `std`; `alloc` is still required.
+//! The generator then needs a random number generator and a [Clock] supplied by the caller.
+//!
+//! ```rust
+//! use rand::rngs::mock::StepRng;
+//! use ulid_generator_rs::ULIDGenerator;
+//!
+//! let mut generator = ULIDGenerator::with_rng_and_clock(StepRng::new(0, 1), || 1_609_459_200_000);
+//! let ulid = generator.generate().unwrap();
+//! ```
+//!
+//! # Command line tool
+//!
+//! ```sh
+//! $ cargo install ulid-generator-rs --features cli
+//! $ ulid -n 3 --monotonic
+//! $ ulid --at 2021-01-01T00:00:00Z --format uuid
+//! $ ulid inspect 01ETGRM6448X1HM0PYWG2KT648
+//! $ ulid convert --from uuid --to canonical < uuids.txt
+//! ```
+//!
+//! # HTTP ID allocation service
+//!
+//! ```sh
+//! $ cargo install ulid-generator-rs --features server
+//! $ ulid-server 127.0.0.1:7070
+//! $ curl 'http://127.0.0.1:7070/ulid?count=3&monotonic=true'
+//! $ curl 'http://127.0.0.1:7070/inspect/01ETGRM6448X1HM0PYWG2KT648'
+//! ```
+//!
 //! # Alternative crates
 //!
 //! - https://github.com/dylanhart/ulid-rs
 //! - https://github.com/huxi/rusty_ulid
 //! - https://github.com/suyash/ulid-rs
+#![cfg_attr(not(feature = "std"), no_std)]
 #![allow(dead_code)]
 
-use std::convert::{TryFrom, TryInto};
-use std::fmt;
-use std::str::FromStr;
+extern crate alloc;
 
-use chrono::{DateTime, Local, TimeZone, Utc};
-use rand::rngs::ThreadRng;
-use rand::Rng;
-use std::cmp::Ordering;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::{TryFrom, TryInto};
+use core::fmt;
+use core::str::FromStr;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, Local, TimeZone};
+use core::cmp::Ordering;
+use core::ops::{Bound, RangeBounds};
+use rand::distributions::Distribution;
+#[cfg(feature = "std")]
+use rand::distributions::Standard;
+use rand::{Rng, RngCore};
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 
+/// Implements the conversions between [ULID] and `$other`, another ULID type holding the same 128-bit
+/// value, and tests that they keep the string form and the ordering.
+#[cfg(any(feature = "rusty_ulid", feature = "ulid"))]
+macro_rules! impl_u128_ulid_conversions {
+  ($other:ty) => {
+    impl From<$other> for $crate::ULID {
+      fn from(ulid: $other) -> Self {
+        Self(u128::from(ulid))
+      }
+    }
+
+    impl From<$crate::ULID> for $other {
+      fn from(ulid: $crate::ULID) -> Self {
+        <$other>::from(ulid.0)
+      }
+    }
+
+    #[cfg(all(test, feature = "std"))]
+    mod tests {
+      use super::*;
+      use $crate::fixtures::ulids;
+      use $crate::ULID;
+
+      #[test]
+      fn string_form() {
+        for ulid in ulids() {
+          let other = <$other>::from(ulid);
+          assert_eq!(other.to_string(), ulid.to_string());
+          assert_eq!(ULID::from(other), ulid);
+          assert_eq!(other.to_string().parse::<ULID>(), Ok(ulid));
+        }
+      }
+
+      #[test]
+      fn ordering() {
+        let mut expected = ulids();
+        let mut others: Vec<$other> = expected.iter().copied().map(<$other>::from).collect();
+        expected.sort();
+        others.sort();
+        assert_eq!(others.into_iter().map(ULID::from).collect::<Vec<_>>(), expected);
+      }
+    }
+  };
+}
+
+#[cfg(feature = "actix-web")]
+pub mod actix_web;
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+#[cfg(feature = "async-graphql")]
+pub mod async_graphql;
+#[cfg(feature = "axum")]
+pub mod axum;
+#[cfg(feature = "bincode")]
+pub mod bincode;
+#[cfg(feature = "borsh")]
+pub mod borsh;
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "clap")]
+pub mod clap;
+#[cfg(feature = "diesel")]
+pub mod diesel;
+#[cfg(feature = "jiff")]
+pub mod jiff;
+#[cfg(feature = "juniper")]
+pub mod juniper;
+#[cfg(feature = "log")]
+pub mod log;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "prost")]
+pub mod prost;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
+#[cfg(feature = "redis")]
+pub mod redis;
+#[cfg(feature = "rusqlite")]
+pub mod rusqlite;
+#[cfg(feature = "rusty_ulid")]
+pub mod rusty_ulid;
+#[cfg(feature = "schemars")]
+pub mod schemars;
 #[cfg(feature = "serde")]
 pub mod serde;
+#[cfg(feature = "sqlx")]
+pub mod sqlx;
+#[cfg(feature = "time")]
+pub mod time;
+#[cfg(feature = "tower")]
+pub mod tower;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+#[cfg(feature = "ulid")]
+pub mod ulid;
+#[cfg(feature = "utoipa")]
+pub mod utoipa;
 #[cfg(feature = "uuid")]
 pub mod uuid;
+#[cfg(feature = "valuable")]
+pub mod valuable;
 
 type ByteArray = Vec<u8>;
 
 /// The error types of [ULID]
+///
+/// New variants may be added in minor releases, so matches must include a wildcard arm.
 #[derive(Debug, Error, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum ULIDError {
   #[error("generate random error: msg = {msg}")]
   GenerateRandomError { msg: String },
@@ -63,14 +209,45 @@ pub enum ULIDError {
   InvalidByteArrayError,
   #[error("ulidString must not exceed '7ZZZZZZZZZZZZZZZZZZZZZZZZZ'!")]
   TimestampOverflowError,
+  #[error("timestamp must not be before the Unix epoch")]
+  TimestampUnderflowError,
 }
 
 const ULID_STRING_LENGTH: u32 = 26;
 const ULID_BYTES_LENGTH: u32 = 16;
 const TIMESTAMP_OVERFLOW_MASK: u64 = 0xffff000000000000;
 
+/// Returns the epoch time in milli seconds of `time`.
+#[cfg(feature = "std")]
+fn epoch_milli_of(time: SystemTime) -> Result<u64, ULIDError> {
+  let millis = time
+    .duration_since(UNIX_EPOCH)
+    .map_err(|_| ULIDError::TimestampUnderflowError)?
+    .as_millis();
+  u64::try_from(millis).map_err(|_| ULIDError::TimestampOverflowError)
+}
+
+/// Returns the current epoch time in milli seconds.
+#[cfg(feature = "std")]
+fn now_epoch_milli() -> Result<u64, ULIDError> {
+  epoch_milli_of(SystemTime::now())
+}
+
 const ENCODING_DIGITS: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
 
+/// The example [ULID] string used in schemas.
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+const ULID_STRING_EXAMPLE: &str = "01ETGRM6448X1HM0PYWG2KT648";
+
+/// Returns a regular expression that matches a canonical [ULID] string.
+///
+/// The first character is limited to `0-7` because the value must fit into 128 bits.
+#[cfg(any(feature = "schemars", feature = "utoipa"))]
+fn ulid_string_pattern() -> String {
+  let digits = core::str::from_utf8(ENCODING_DIGITS).unwrap();
+  alloc::format!("^[{}][{}]{{{}}}$", &digits[..8], digits, ULID_STRING_LENGTH - 1)
+}
+
 #[rustfmt::skip]
 static DECODING_DIGITS: [Option<u8>; 123] = [
   // 0
@@ -286,7 +463,26 @@ pub enum Endian {
 }
 
 /// This struct is [ULID].
+///
+/// A [ULID] has the same in-memory layout as a `u128`, so its bytes are in native byte order
+/// (see [`u128::to_ne_bytes`]). With the `bytemuck` and `zerocopy` features, slices of [ULID] can be
+/// cast from and to bytes on that basis. With the `rkyv` feature, the archived form is always
+/// little-endian regardless of the target.
+///
+/// Native byte order is neither portable across targets nor, on little-endian targets, sorted like
+/// [ULID]s when compared byte by byte. For files, memory maps and indexes, use [ULIDBytes] instead.
 #[derive(Debug, Copy, Clone, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(zerocopy::FromBytes, zerocopy::IntoBytes, zerocopy::Immutable, zerocopy::KnownLayout)
+)]
+#[cfg_attr(
+  feature = "rkyv",
+  derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+  rkyv(derive(Debug, Hash, Eq, Ord, PartialEq, PartialOrd), compare(PartialEq, PartialOrd))
+)]
+#[repr(transparent)]
 pub struct ULID(u128);
 
 unsafe impl Send for ULID {}
@@ -294,15 +490,119 @@ unsafe impl Sync for ULID {}
 
 impl fmt::Display for ULID {
   fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-    f.write_str(&self.to_string())
+    let encoded = append_crockford_u128(self.0);
+    f.write_str(core::str::from_utf8(&encoded).unwrap())
+  }
+}
+
+/// The 16 big-endian bytes of a [ULID].
+///
+/// Unlike [ULID], the layout is the same on every target and is aligned to 1 byte, and comparing the
+/// bytes orders like the [ULID]s they came from. With the `bytemuck` and `zerocopy` features, slices
+/// of [ULIDBytes] can be cast from and to bytes, e.g. of a memory-mapped file.
+///
+/// # Example
+///
+/// ```rust
+/// use ulid_generator_rs::{ULIDBytes, ULID};
+///
+/// let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+/// let bytes = ULIDBytes::from(ulid);
+/// assert_eq!(bytes.0[0], 0x01);
+/// assert_eq!(ULID::from(bytes), ulid);
+/// ```
+#[derive(Debug, Copy, Clone, Default, Hash, Eq, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "bytemuck", derive(bytemuck::Pod, bytemuck::Zeroable))]
+#[cfg_attr(
+  feature = "zerocopy",
+  derive(
+    zerocopy::FromBytes,
+    zerocopy::IntoBytes,
+    zerocopy::Immutable,
+    zerocopy::KnownLayout,
+    zerocopy::Unaligned
+  )
+)]
+#[repr(transparent)]
+pub struct ULIDBytes(pub [u8; 16]);
+
+impl From<ULID> for ULIDBytes {
+  fn from(ulid: ULID) -> Self {
+    Self(ulid.0.to_be_bytes())
+  }
+}
+
+impl From<ULIDBytes> for ULID {
+  fn from(bytes: ULIDBytes) -> Self {
+    ULID(u128::from_be_bytes(bytes.0))
+  }
+}
+
+/// A [ULID] stored as its 16 big-endian bytes where an integration stores a [ULID] differently by
+/// default, e.g. as a `bytea` instead of a `uuid` column on PostgreSQL.
+#[derive(Debug, Copy, Clone, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ULIDAsBytes(pub ULID);
+
+impl From<ULID> for ULIDAsBytes {
+  fn from(ulid: ULID) -> Self {
+    Self(ulid)
+  }
+}
+
+impl From<ULIDAsBytes> for ULID {
+  fn from(value: ULIDAsBytes) -> Self {
+    value.0
+  }
+}
+
+/// A [ULID] stored as its 26-character canonical string, e.g. in a `TEXT` column.
+#[derive(Debug, Copy, Clone, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ULIDAsText(pub ULID);
+
+impl From<ULID> for ULIDAsText {
+  fn from(ulid: ULID) -> Self {
+    Self(ulid)
+  }
+}
+
+impl From<ULIDAsText> for ULID {
+  fn from(value: ULIDAsText) -> Self {
+    value.0
   }
 }
 
 const RANDOM_MSB_MASK: u64 = 0xffff;
 const TIMESTAMP_MSB_MASK: u64 = 0xffffffffffff0000;
+const MAX_TIMESTAMP: u64 = !TIMESTAMP_OVERFLOW_MASK;
+const RANDOMNESS_MASK: u128 = (1 << 80) - 1;
 
 /// implements for [ULID].
 impl ULID {
+  /// The largest [ULID], `7ZZZZZZZZZZZZZZZZZZZZZZZZZ`.
+  pub const MAX: ULID = ULID(u128::MAX);
+  /// The smallest [ULID], `00000000000000000000000000`.
+  pub const MIN: ULID = ULID(0);
+
+  /// Lays out the 48-bit `timestamp` and the lower 80 bits of `randomness` as a [ULID].
+  const fn from_timestamp_and_randomness(timestamp: u64, randomness: u128) -> Self {
+    Self((timestamp as u128) << 80 | (randomness & RANDOMNESS_MASK))
+  }
+
+  /// Picks a [ULID] for property-based testing from arbitrary inputs.
+  ///
+  /// Half of the `selector` values pick an edge value: [ULID::MIN], [ULID::MAX], a [ULID] whose
+  /// randomness is all ones just before [ULID::increment] overflows, or one with the largest timestamp.
+  #[cfg(any(feature = "quickcheck", feature = "arbitrary"))]
+  fn arbitrary_from(selector: u8, timestamp: u64, randomness: u128) -> Self {
+    match selector % 8 {
+      0 => Self::MIN,
+      1 => Self::MAX,
+      2 => Self::from_timestamp_and_randomness(timestamp & MAX_TIMESTAMP, RANDOMNESS_MASK),
+      3 => Self::from_timestamp_and_randomness(MAX_TIMESTAMP, randomness),
+      _ => Self::from_timestamp_and_randomness(timestamp & MAX_TIMESTAMP, randomness),
+    }
+  }
+
   /// The Constructor for [ULID].
   ///
   /// # Example
@@ -336,7 +636,6 @@ impl ULID {
   /// let str: String = ulid.to_string();
   /// println!("{}", str); // "01ETGRM6448X1HM0PYWG2KT648"
   /// ```
-  #[allow(clippy::unknown_clippy_lints)]
   #[allow(clippy::inherent_to_string_shadow_display)]
   #[must_use]
   pub fn to_string(&self) -> String {
@@ -416,6 +715,39 @@ impl ULID {
     (self.0 >> 80) as i64
   }
 
+  /// Returns the lower 80 random bits of a [ULID].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ulid_generator_rs::ULID;
+  ///
+  /// let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+  /// assert_eq!(ulid.randomness(), 0x47431a02dee4053d1888);
+  /// ```
+  #[must_use]
+  pub const fn randomness(&self) -> u128 {
+    self.0 & RANDOMNESS_MASK
+  }
+
+  /// Converts a [ULID] to a [SystemTime].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use std::time::SystemTime;
+  /// use ulid_generator_rs::{ULIDGenerator, ULID};
+  ///
+  /// let mut generator: ULIDGenerator = ULIDGenerator::new();
+  /// let ulid: ULID = generator.generate().unwrap();
+  /// let system_time: SystemTime = ulid.to_system_time();
+  /// ```
+  #[cfg(feature = "std")]
+  #[must_use]
+  pub fn to_system_time(&self) -> SystemTime {
+    UNIX_EPOCH + Duration::from_millis(self.to_epoch_milli_as_long() as u64)
+  }
+
   /// Converts a [ULID] to a [`DateTime<Local>`]
   ///
   /// # Example
@@ -428,9 +760,10 @@ impl ULID {
   /// let ulid: ULID = generator.generate().unwrap();
   /// let date_time: DateTime<Local> = ulid.to_date_time();
   /// ```
+  #[cfg(feature = "chrono")]
   #[must_use]
   pub fn to_date_time(&self) -> DateTime<Local> {
-    Local.timestamp_millis(self.to_epoch_milli_as_long())
+    Local.timestamp_millis_opt(self.to_epoch_milli_as_long()).unwrap()
   }
 
   /// Converts a [ULID] to a byte array.
@@ -512,6 +845,17 @@ impl From<u128> for ULID {
   }
 }
 
+impl From<ULID> for u128 {
+  /// ```rust
+  /// use ulid_generator_rs::ULID;
+  ///
+  /// let value: u128 = ULID::new(1945530789360716160560926739305506752).into();
+  /// ```
+  fn from(ulid: ULID) -> Self {
+    ulid.0
+  }
+}
+
 impl From<(u64, u64)> for ULID {
   /// ```rust
   /// use ulid_generator_rs::ULID;
@@ -524,6 +868,21 @@ impl From<(u64, u64)> for ULID {
   }
 }
 
+#[cfg(feature = "rkyv")]
+impl From<&ArchivedULID> for ULID {
+  /// ```rust
+  /// use ulid_generator_rs::{ArchivedULID, ULID};
+  ///
+  /// let ulid: ULID = 1945530789360716160560926739305506752.into();
+  /// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&ulid).unwrap();
+  /// let archived = rkyv::access::<ArchivedULID, rkyv::rancor::Error>(&bytes).unwrap();
+  /// assert_eq!(ULID::from(archived), ulid);
+  /// ```
+  fn from(archived: &ArchivedULID) -> Self {
+    Self(archived.0.to_native())
+  }
+}
+
 impl TryFrom<ByteArray> for ULID {
   type Error = ULIDError;
 
@@ -540,34 +899,170 @@ impl TryFrom<ByteArray> for ULID {
   }
 }
 
+#[cfg(feature = "std")]
+impl Distribution<ULID> for Standard {
+  /// Draws a [ULID] with the current time and random bits, like [`ULIDGenerator::generate`].
+  ///
+  /// ```rust
+  /// use rand::Rng;
+  /// use ulid_generator_rs::ULID;
+  ///
+  /// let ulid: ULID = rand::thread_rng().gen();
+  /// ```
+  fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> ULID {
+    let timestamp = now_epoch_milli().unwrap_or_default();
+    ULID::from_timestamp_and_randomness(timestamp & MAX_TIMESTAMP, rng.gen())
+  }
+}
+
+/// A [Distribution] of [ULID]s whose epoch time in milli seconds is within the range.
+///
+/// Bounds beyond `2^48-1` are clamped to it. Sampling panics if the range is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use rand::Rng;
+/// use ulid_generator_rs::{ULIDWithin, ULID};
+///
+/// let ulid: ULID = rand::thread_rng().sample(ULIDWithin(1_600_000_000_000..1_700_000_000_000));
+/// assert!((1_600_000_000_000..1_700_000_000_000).contains(&ulid.to_epoch_milli_as_long()));
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ULIDWithin<R>(pub R);
+
+impl<R> Distribution<ULID> for ULIDWithin<R>
+where
+  R: RangeBounds<u64>,
+{
+  fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> ULID {
+    let low = match self.0.start_bound() {
+      Bound::Included(&start) => start,
+      Bound::Excluded(&start) => start.checked_add(1).expect("ULIDWithin range is empty"),
+      Bound::Unbounded => 0,
+    };
+    let high = match self.0.end_bound() {
+      Bound::Included(&end) => end,
+      Bound::Excluded(&end) => end.checked_sub(1).expect("ULIDWithin range is empty"),
+      Bound::Unbounded => MAX_TIMESTAMP,
+    };
+    let timestamp = rng.gen_range(low.min(MAX_TIMESTAMP)..=high.min(MAX_TIMESTAMP));
+    ULID::from_timestamp_and_randomness(timestamp, rng.gen())
+  }
+}
+
+/// A source of the current time for [ULIDGenerator].
+///
+/// Any `Fn() -> u64` returning the epoch time in milli seconds is a [Clock], which is how a
+/// clock is supplied on targets without `std`.
+pub trait Clock {
+  /// Returns the current epoch time in milli seconds.
+  fn now_epoch_milli(&self) -> Result<u64, ULIDError>;
+}
+
+impl<F> Clock for F
+where
+  F: Fn() -> u64,
+{
+  fn now_epoch_milli(&self) -> Result<u64, ULIDError> {
+    Ok(self())
+  }
+}
+
+/// The [Clock] backed by [SystemTime].
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+  fn now_epoch_milli(&self) -> Result<u64, ULIDError> {
+    now_epoch_milli()
+  }
+}
+
+/// The random number generator backed by [`rand::thread_rng`].
+///
+/// Unlike `ThreadRng`, it is [Send] and [Sync], since it looks up the generator of the calling thread on every call.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, Default)]
+pub struct ThreadLocalRng;
+
+#[cfg(feature = "std")]
+impl RngCore for ThreadLocalRng {
+  fn next_u32(&mut self) -> u32 {
+    rand::thread_rng().next_u32()
+  }
+
+  fn next_u64(&mut self) -> u64 {
+    rand::thread_rng().next_u64()
+  }
+
+  fn fill_bytes(&mut self, dest: &mut [u8]) {
+    rand::thread_rng().fill_bytes(dest)
+  }
+
+  fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+    rand::thread_rng().try_fill_bytes(dest)
+  }
+}
+
 /// This is the [ULID] Generator.
+///
+/// With the `std` feature, [`ULIDGenerator::new`] uses [ThreadLocalRng] and [SystemClock].
+/// Otherwise, use [`ULIDGenerator::with_rng_and_clock`] to supply them.
+#[cfg(feature = "std")]
 #[derive(Clone, Debug)]
-pub struct ULIDGenerator {
-  rng: ThreadRng,
+pub struct ULIDGenerator<R = ThreadLocalRng, C = SystemClock> {
+  rng: R,
+  clock: C,
 }
 
-unsafe impl Send for ULIDGenerator {}
-unsafe impl Sync for ULIDGenerator {}
+/// This is the [ULID] Generator.
+///
+/// Use [`ULIDGenerator::with_rng_and_clock`] to supply a random number generator and a [Clock].
+#[cfg(not(feature = "std"))]
+#[derive(Clone, Debug)]
+pub struct ULIDGenerator<R, C> {
+  rng: R,
+  clock: C,
+}
 
+#[cfg(feature = "std")]
 impl ULIDGenerator {
   /// The Constructor for [ULIDGenerator].
   #[must_use]
   pub fn new() -> Self {
-    Self {
-      rng: rand::thread_rng(),
-    }
+    Self::with_rng_and_clock(ThreadLocalRng, SystemClock)
   }
+}
 
-  fn internal_generate<F>(&mut self, time_stamp_f: F) -> Result<ULID, ULIDError>
-  where
-    F: Fn() -> u64, {
-    let timestamp = time_stamp_f();
+impl<R, C> ULIDGenerator<R, C>
+where
+  R: RngCore,
+  C: Clock,
+{
+  /// The Constructor for [ULIDGenerator] with the given random number generator and [Clock].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use rand::rngs::mock::StepRng;
+  /// use ulid_generator_rs::ULIDGenerator;
+  ///
+  /// let mut generator = ULIDGenerator::with_rng_and_clock(StepRng::new(0, 1), || 1_609_459_200_000);
+  /// let ulid = generator.generate().unwrap();
+  /// assert_eq!(ulid.to_epoch_milli_as_long(), 1_609_459_200_000);
+  /// ```
+  pub fn with_rng_and_clock(rng: R, clock: C) -> Self {
+    Self { rng, clock }
+  }
+
+  fn internal_generate(&mut self, timestamp: u64) -> Result<ULID, ULIDError> {
     if (timestamp & TIMESTAMP_OVERFLOW_MASK) != 0 {
       Err(ULIDError::TimestampOverflowError)
     } else {
-      let (most_rnd, least_significant_bits): (u16, u64) = self.rng.gen();
-      let most_significant_bits = timestamp << 16 | u64::from(most_rnd);
-      Ok(ULID::from((most_significant_bits, least_significant_bits)))
+      Ok(ULID::from_timestamp_and_randomness(timestamp, self.rng.gen()))
     }
   }
 
@@ -584,7 +1079,30 @@ impl ULIDGenerator {
   /// let ulid = generator.generate().unwrap();
   /// ```
   pub fn generate(&mut self) -> Result<ULID, ULIDError> {
-    self.internal_generate(|| Utc::now().timestamp_millis() as u64)
+    let timestamp = self.clock.now_epoch_milli()?;
+    self.internal_generate(timestamp)
+  }
+
+  /// Generate a [ULID] at the given time.
+  ///
+  /// `time` is anything convertible into a [SystemTime], such as a `chrono::DateTime`,
+  /// a `time::OffsetDateTime` or a `jiff::Timestamp`.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use std::time::{Duration, UNIX_EPOCH};
+  /// use ulid_generator_rs::ULIDGenerator;
+  ///
+  /// let mut generator = ULIDGenerator::new();
+  /// let ulid = generator.generate_at(UNIX_EPOCH + Duration::from_millis(1_609_459_200_000)).unwrap();
+  /// assert_eq!(ulid.to_epoch_milli_as_long(), 1_609_459_200_000);
+  /// ```
+  #[cfg(feature = "std")]
+  pub fn generate_at<T>(&mut self, time: T) -> Result<ULID, ULIDError>
+  where
+    T: Into<SystemTime>, {
+    self.internal_generate(epoch_milli_of(time.into())?)
   }
 
   /// Generate a monotonic [ULID].
@@ -603,11 +1121,11 @@ impl ULIDGenerator {
   /// let ulid = generator.generate_monotonic(&previous_id).unwrap();
   /// ```
   pub fn generate_monotonic(&mut self, previous_id: &ULID) -> Result<ULID, ULIDError> {
-    let timestamp = Utc::now().timestamp_millis();
-    if previous_id.to_epoch_milli_as_long() == timestamp {
+    let timestamp = self.clock.now_epoch_milli()?;
+    if previous_id.to_epoch_milli_as_long() == timestamp as i64 {
       Ok(previous_id.increment())
     } else {
-      self.internal_generate(|| timestamp as u64)
+      self.internal_generate(timestamp)
     }
   }
 
@@ -633,22 +1151,87 @@ impl ULIDGenerator {
       Ok(Some(result))
     }
   }
+
+  /// Generate a [ULID] that is always greater than `previous_id`.
+  ///
+  /// Returns the [ULID] of [`ULIDGenerator::generate_monotonic`] if it is greater than `previous_id`.
+  /// Otherwise, i.e. if the clock fails, went backwards or the randomness of `previous_id` is all ones,
+  /// returns `previous_id` plus one, which carries an overflow of the randomness into the next millisecond.
+  /// Returns an error only if `previous_id` is [`ULID::MAX`].
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use rand::rngs::mock::StepRng;
+  /// use ulid_generator_rs::{ULIDGenerator, ULID};
+  ///
+  /// let mut generator = ULIDGenerator::with_rng_and_clock(StepRng::new(0, 1), || 1_609_459_200_000);
+  /// // The randomness of `previous_id` is all ones.
+  /// let previous_id = ULID::new((1_609_459_200_001 << 80) | ((1 << 80) - 1));
+  /// let ulid = generator.generate_next(&previous_id).unwrap();
+  /// assert_eq!(ulid, ULID::new(1_609_459_200_002 << 80));
+  /// ```
+  pub fn generate_next(&mut self, previous_id: &ULID) -> Result<ULID, ULIDError> {
+    match self.generate_monotonic(previous_id) {
+      Ok(ulid) if ulid > *previous_id => Ok(ulid),
+      _ => previous_id
+        .0
+        .checked_add(1)
+        .map(ULID)
+        .ok_or(ULIDError::TimestampOverflowError),
+    }
+  }
 }
 
+#[cfg(feature = "std")]
 impl Default for ULIDGenerator {
   fn default() -> Self {
     ULIDGenerator::new()
   }
 }
 
+/// Values shared by the tests of this crate and of its integration modules.
 #[cfg(test)]
+mod fixtures {
+  use super::*;
+
+  /// The canonical string of [GOLDEN].
+  pub(crate) const GOLDEN_STR: &str = "01ETGRM6448X1HM0PYWG2KT648";
+  /// The big-endian bytes of [GOLDEN].
+  pub(crate) const GOLDEN_BYTES: [u8; 16] = [
+    0x01, 0x76, 0xa1, 0x8a, 0x18, 0x84, 0x47, 0x43, 0x1a, 0x02, 0xde, 0xe4, 0x05, 0x3d, 0x18, 0x88,
+  ];
+  /// The epoch milli seconds of [GOLDEN], `2020-12-27T00:12:30.724Z`.
+  pub(crate) const GOLDEN_EPOCH_MILLI: u64 = 1_609_027_950_724;
+  /// A [ULID] with known string, bytes and timestamp.
+  pub(crate) const GOLDEN: ULID = ULID(u128::from_be_bytes(GOLDEN_BYTES));
+
+  /// Returns generated [ULID]s followed by edge values, some of which sort differently by their
+  /// little-endian bytes.
+  #[cfg(feature = "std")]
+  pub(crate) fn ulids() -> alloc::vec::Vec<ULID> {
+    let mut generator = ULIDGenerator::new();
+    let mut ulids: alloc::vec::Vec<ULID> = (0..8).map(|_| generator.generate().unwrap()).collect();
+    ulids.extend([
+      ULID::MIN,
+      ULID::new(1),
+      ULID::new(1 << 8),
+      ULID::new(1 << 80),
+      ULID::new(u128::MAX >> 2),
+      ULID::MAX,
+    ]);
+    ulids
+  }
+}
+
+#[cfg(all(test, feature = "std"))]
 mod tests {
   use super::*;
   use std::convert::TryInto;
 
   #[test]
   fn generate() -> Result<(), ULIDError> {
-    let now = Local::now().timestamp_millis();
+    let now = now_epoch_milli().unwrap() as i64;
     let ulid = ULIDGenerator::new().generate()?;
     assert!(now <= ulid.to_epoch_milli_as_long());
     Ok(())
@@ -672,6 +1255,7 @@ mod tests {
     println!("ulid = {}", ulid);
   }
 
+  #[cfg(feature = "chrono")]
   #[test]
   fn to_date_time() {
     let ulid: ULID = 1945530789360716160560926739305506752.into();
@@ -690,9 +1274,24 @@ mod tests {
     Ok(())
   }
 
+  #[test]
+  fn golden() {
+    use fixtures::*;
+    assert_eq!(GOLDEN.to_string(), GOLDEN_STR);
+    assert_eq!(GOLDEN.to_byte_array(Endian::BE), GOLDEN_BYTES);
+    assert_eq!(GOLDEN.to_epoch_milli_as_long(), GOLDEN_EPOCH_MILLI as i64);
+  }
+
+  #[test]
+  fn randomness() {
+    assert_eq!(fixtures::GOLDEN.randomness(), 0x47431a02dee4053d1888);
+    assert_eq!(ULID::MAX.randomness(), RANDOMNESS_MASK);
+    assert_eq!(ULID::from_timestamp_and_randomness(MAX_TIMESTAMP, 0).randomness(), 0);
+  }
+
   #[test]
   fn parse_string() -> Result<(), ULIDError> {
-    let s = "01ETGRM6448X1HM0PYWG2KT648";
+    let s = fixtures::GOLDEN_STR;
     let ulid = s.parse::<ULID>()?;
     assert_eq!(ulid.to_string(), s);
     Ok(())
@@ -715,4 +1314,179 @@ mod tests {
     assert!(previous_id < ulid.unwrap());
     Ok(())
   }
+
+  #[test]
+  fn standard_distribution() {
+    let now = now_epoch_milli().unwrap() as i64;
+    let ulid: ULID = rand::thread_rng().gen();
+    assert!(now <= ulid.to_epoch_milli_as_long());
+  }
+
+  #[test]
+  fn ulid_within() {
+    let mut rng = rand::thread_rng();
+    for _ in 0..100 {
+      let ulid = rng.sample(ULIDWithin(1_000..1_010));
+      assert!((1_000..1_010).contains(&ulid.to_epoch_milli_as_long()));
+    }
+    assert_eq!(rng.sample(ULIDWithin(7..=7)).to_epoch_milli_as_long(), 7);
+    let ulid = rng.sample(ULIDWithin(u64::MAX..));
+    assert_eq!(ulid.to_epoch_milli_as_long(), MAX_TIMESTAMP as i64);
+  }
+
+  #[test]
+  fn generate_at() -> Result<(), ULIDError> {
+    let mut generator = ULIDGenerator::new();
+    let time = UNIX_EPOCH + Duration::from_millis(1_609_459_200_000);
+    let ulid = generator.generate_at(time)?;
+    assert_eq!(ulid.to_system_time(), time);
+    assert_eq!(
+      generator.generate_at(UNIX_EPOCH - Duration::from_millis(1)),
+      Err(ULIDError::TimestampUnderflowError)
+    );
+    assert_eq!(
+      generator.generate_at(UNIX_EPOCH + Duration::from_millis(MAX_TIMESTAMP + 1)),
+      Err(ULIDError::TimestampOverflowError)
+    );
+    Ok(())
+  }
+
+  #[test]
+  fn with_rng_and_clock() -> Result<(), ULIDError> {
+    let mut generator = ULIDGenerator::with_rng_and_clock(rand::rngs::mock::StepRng::new(0, 1), || 1_609_459_200_000);
+    let previous_id = generator.generate()?;
+    assert_eq!(previous_id.to_epoch_milli_as_long(), 1_609_459_200_000);
+    assert_eq!(generator.generate_monotonic(&previous_id)?, previous_id.increment());
+    let mut generator = ULIDGenerator::with_rng_and_clock(rand::rngs::mock::StepRng::new(0, 1), || MAX_TIMESTAMP + 1);
+    assert_eq!(generator.generate(), Err(ULIDError::TimestampOverflowError));
+    Ok(())
+  }
+
+  #[test]
+  fn generate_next() -> Result<(), ULIDError> {
+    let now = 1_609_459_200_000;
+    let mut generator = ULIDGenerator::with_rng_and_clock(rand::rngs::mock::StepRng::new(0, 1), || now);
+    let previous_id = ULID::from_timestamp_and_randomness(now - 1, 7);
+    assert!(generator.generate_next(&previous_id)?.to_epoch_milli_as_long() == now as i64);
+    // The same millisecond increments the randomness.
+    let previous_id = ULID::from_timestamp_and_randomness(now, 7);
+    assert_eq!(generator.generate_next(&previous_id)?, previous_id.increment());
+    // The randomness overflows into the next millisecond, whether the clock is at or behind `previous_id`.
+    for timestamp in [now, now + 10] {
+      let previous_id = ULID::from_timestamp_and_randomness(timestamp, RANDOMNESS_MASK);
+      assert_ne!(previous_id, ULID::MAX);
+      assert_eq!(
+        generator.generate_next(&previous_id)?,
+        ULID::from_timestamp_and_randomness(timestamp + 1, 0)
+      );
+    }
+    assert_eq!(
+      generator.generate_next(&ULID::MAX),
+      Err(ULIDError::TimestampOverflowError)
+    );
+    let mut generator = ULIDGenerator::with_rng_and_clock(rand::rngs::mock::StepRng::new(0, 1), || u64::MAX);
+    assert_eq!(generator.generate_next(&previous_id)?, previous_id.increment());
+    Ok(())
+  }
+
+  #[test]
+  fn send_and_sync() {
+    fn assert_send_and_sync<T: Send + Sync>() {}
+    assert_send_and_sync::<ULIDGenerator>();
+    assert_send_and_sync::<ULIDGenerator<rand::rngs::StdRng, SystemClock>>();
+  }
+
+  #[test]
+  fn shared_across_threads() -> Result<(), ULIDError> {
+    let generator = std::sync::Arc::new(std::sync::Mutex::new(ULIDGenerator::new()));
+    let handles: Vec<_> = (0..4)
+      .map(|_| {
+        let generator = std::sync::Arc::clone(&generator);
+        std::thread::spawn(move || generator.lock().unwrap().generate())
+      })
+      .collect();
+    let mut ulids = handles
+      .into_iter()
+      .map(|handle| handle.join().unwrap())
+      .collect::<Result<Vec<_>, _>>()?;
+    ulids.sort();
+    ulids.dedup();
+    assert_eq!(ulids.len(), 4);
+    Ok(())
+  }
+
+  #[cfg(feature = "bytemuck")]
+  #[test]
+  fn bytemuck() {
+    let ulids: [ULID; 2] = [ULID::new(1945530789360716160560926739305506752), ULID::new(1)];
+    let bytes: &[u8] = bytemuck::cast_slice(&ulids);
+    assert_eq!(bytes.len(), 32);
+    assert_eq!(bytes[..16], 1945530789360716160560926739305506752u128.to_ne_bytes());
+    let round_trip: &[ULID] = bytemuck::cast_slice(bytes);
+    assert_eq!(round_trip, &ulids);
+    assert_eq!(bytemuck::pod_read_unaligned::<ULID>(&bytes[16..]), ULID::new(1));
+    assert_eq!(<ULID as bytemuck::Zeroable>::zeroed(), ULID::new(0));
+  }
+
+  #[cfg(feature = "zerocopy")]
+  #[test]
+  fn zerocopy() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    let ulids: [ULID; 2] = [ULID::new(1945530789360716160560926739305506752), ULID::new(1)];
+    let bytes: &[u8] = ulids.as_bytes();
+    assert_eq!(bytes[..16], 1945530789360716160560926739305506752u128.to_ne_bytes());
+    let round_trip = <[ULID]>::ref_from_bytes(bytes).unwrap();
+    assert_eq!(round_trip, &ulids);
+    assert_eq!(ULID::read_from_bytes(&bytes[16..]), Ok(ULID::new(1)));
+  }
+
+  #[cfg(feature = "rkyv")]
+  #[test]
+  fn rkyv() {
+    let ulids: Vec<ULID> = vec![ULID::new(1945530789360716160560926739305506752), ULID::new(1)];
+    let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&ulids).unwrap();
+    assert_eq!(bytes[..16], 1945530789360716160560926739305506752u128.to_le_bytes());
+    let archived = rkyv::access::<rkyv::Archived<Vec<ULID>>, rkyv::rancor::Error>(&bytes).unwrap();
+    assert_eq!(archived[0], ulids[0]);
+    assert!(archived[1] < archived[0]);
+    let deserialized = rkyv::deserialize::<Vec<ULID>, rkyv::rancor::Error>(archived).unwrap();
+    assert_eq!(deserialized, ulids);
+  }
+
+  #[test]
+  fn ulid_bytes() {
+    let mut ulids = fixtures::ulids();
+    let mut bytes: Vec<ULIDBytes> = ulids.iter().copied().map(ULIDBytes::from).collect();
+    ulids.sort();
+    bytes.sort();
+    assert_eq!(bytes.into_iter().map(ULID::from).collect::<Vec<_>>(), ulids);
+    assert_eq!(ULIDBytes::from(fixtures::GOLDEN).0, fixtures::GOLDEN_BYTES);
+  }
+
+  #[cfg(feature = "bytemuck")]
+  #[test]
+  fn ulid_bytes_bytemuck() {
+    let ulids = [ULIDBytes::from(fixtures::GOLDEN), ULIDBytes::from(ULID::new(1))];
+    let bytes: &[u8] = bytemuck::cast_slice(&ulids);
+    assert_eq!(bytes[..16], fixtures::GOLDEN_BYTES);
+    assert_eq!(bytes[31], 1);
+    let round_trip: &[ULIDBytes] = bytemuck::cast_slice(&bytes[16..]);
+    assert_eq!(ULID::from(round_trip[0]), ULID::new(1));
+  }
+
+  #[cfg(feature = "zerocopy")]
+  #[test]
+  fn ulid_bytes_zerocopy() {
+    use zerocopy::{FromBytes, IntoBytes};
+
+    let ulids = [ULIDBytes::from(fixtures::GOLDEN), ULIDBytes::from(ULID::new(1))];
+    let bytes: &[u8] = ulids.as_bytes();
+    assert_eq!(bytes[..16], fixtures::GOLDEN_BYTES);
+    // The alignment is 1, so any offset can be cast.
+    let mut unaligned = vec![0u8];
+    unaligned.extend_from_slice(bytes);
+    let round_trip = <[ULIDBytes]>::ref_from_bytes(&unaligned[1..]).unwrap();
+    assert_eq!(round_trip, &ulids);
+  }
 }
@@ -0,0 +1,40 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [log](https://github.com/rust-lang/log) key-value support.
+//!
+//! A [ULID] is recorded as its canonical string, formatted without allocating.
+//!
+//! ```rust
+//! use ulid_generator_rs::ULID;
+//!
+//! let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+//! log::info!(ulid, ulid_timestamp = ulid.to_epoch_milli_as_long(); "created");
+//! ```
+
+use log::kv::{ToValue, Value};
+
+use crate::ULID;
+
+impl ToValue for ULID {
+  fn to_value(&self) -> Value<'_> {
+    Value::from_display(self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_STR};
+  use alloc::string::ToString;
+
+  #[test]
+  fn to_value() {
+    assert_eq!(GOLDEN.to_value().to_string(), GOLDEN_STR);
+  }
+}
@@ -0,0 +1,88 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [postgres-types](https://github.com/sfackler/rust-postgres) support for `postgres` and `tokio-postgres`.
+//!
+//! A [ULID] is accepted for `UUID` and `BYTEA` columns as its 16 big-endian bytes,
+//! and for `TEXT`, `VARCHAR` and `BPCHAR` columns as its canonical string.
+
+use std::error::Error;
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+
+use crate::{Endian, ULID};
+
+fn is_text(ty: &Type) -> bool {
+  matches!(*ty, Type::TEXT | Type::VARCHAR | Type::BPCHAR)
+}
+
+impl ToSql for ULID {
+  to_sql_checked!();
+
+  fn to_sql(&self, ty: &Type, out: &mut BytesMut) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+    if is_text(ty) {
+      out.put_slice(self.to_string().as_bytes());
+    } else {
+      out.put_slice(&self.to_byte_array(Endian::BE));
+    }
+    Ok(IsNull::No)
+  }
+
+  fn accepts(ty: &Type) -> bool {
+    matches!(*ty, Type::UUID | Type::BYTEA) || is_text(ty)
+  }
+}
+
+impl<'a> FromSql<'a> for ULID {
+  fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+    if is_text(ty) {
+      Ok(std::str::from_utf8(raw)?.parse::<ULID>()?)
+    } else {
+      Ok(ULID::parse_from_byte_array(raw.to_vec(), Endian::BE)?)
+    }
+  }
+
+  fn accepts(ty: &Type) -> bool {
+    <Self as ToSql>::accepts(ty)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_BYTES, GOLDEN_STR};
+
+  #[test]
+  fn binary_wire_format() {
+    for ty in [Type::UUID, Type::BYTEA] {
+      let mut buf = BytesMut::new();
+      GOLDEN.to_sql_checked(&ty, &mut buf).unwrap();
+      assert_eq!(&buf[..], &GOLDEN_BYTES[..]);
+      assert_eq!(ULID::from_sql(&ty, &buf).unwrap(), GOLDEN);
+    }
+  }
+
+  #[test]
+  fn text_wire_format() {
+    for ty in [Type::TEXT, Type::VARCHAR, Type::BPCHAR] {
+      let mut buf = BytesMut::new();
+      GOLDEN.to_sql_checked(&ty, &mut buf).unwrap();
+      assert_eq!(&buf[..], GOLDEN_STR.as_bytes());
+      assert_eq!(ULID::from_sql(&ty, &buf).unwrap(), GOLDEN);
+    }
+  }
+
+  #[test]
+  fn invalid_input() {
+    let mut buf = BytesMut::new();
+    assert!(ULID::new(1).to_sql_checked(&Type::INT8, &mut buf).is_err());
+    assert!(ULID::from_sql(&Type::UUID, &[0; 15]).is_err());
+    assert!(ULID::from_sql(&Type::TEXT, b"01ETGRM6448X1HM0PYWG2KT64U").is_err());
+  }
+}
@@ -0,0 +1,92 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [proptest](https://github.com/proptest-rs/proptest) support.
+//!
+//! `any::<ULID>()` favors edge values: [ULID::MIN], [ULID::MAX], IDs whose randomness is all ones
+//! just before [ULID::increment] overflows, and IDs with the largest timestamp `2^48-1`.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRunner;
+//! use ulid_generator_rs::ULID;
+//!
+//! TestRunner::default()
+//!   .run(&any::<ULID>(), |ulid| {
+//!     prop_assert_eq!(ulid.to_string().parse::<ULID>().unwrap(), ulid);
+//!     Ok(())
+//!   })
+//!   .unwrap();
+//! ```
+
+use proptest::collection::{vec, SizeRange};
+use proptest::prelude::*;
+
+use crate::{MAX_TIMESTAMP, RANDOMNESS_MASK, ULID};
+
+impl Arbitrary for ULID {
+  type Parameters = ();
+  type Strategy = BoxedStrategy<ULID>;
+
+  fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+    prop_oneof![
+      4 => timestamp().prop_flat_map(with_timestamp),
+      1 => Just(ULID::MIN),
+      1 => Just(ULID::MAX),
+      1 => timestamp().prop_map(|timestamp| ULID::from_timestamp_and_randomness(timestamp, RANDOMNESS_MASK)),
+      1 => with_timestamp(MAX_TIMESTAMP),
+    ]
+    .boxed()
+  }
+}
+
+/// Returns a strategy of timestamps that fit into a [ULID], up to `2^48-1`.
+pub fn timestamp() -> impl Strategy<Value = u64> + Clone {
+  0..=MAX_TIMESTAMP
+}
+
+/// Returns a strategy of [ULID]s with the given `timestamp` and arbitrary randomness.
+///
+/// # Panics
+///
+/// Panics if `timestamp` exceeds `2^48-1`.
+pub fn with_timestamp(timestamp: u64) -> impl Strategy<Value = ULID> + Clone {
+  assert!(timestamp <= MAX_TIMESTAMP, "timestamp must not exceed 2^48-1");
+  any::<u128>().prop_map(move |randomness| ULID::from_timestamp_and_randomness(timestamp, randomness))
+}
+
+/// Returns a strategy of [ULID] vectors whose elements share one arbitrary timestamp and vary in randomness.
+pub fn same_timestamp(size: impl Into<SizeRange>) -> impl Strategy<Value = Vec<ULID>> {
+  let size = size.into();
+  timestamp().prop_flat_map(move |timestamp| vec(with_timestamp(timestamp), size.clone()))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  proptest! {
+    #[test]
+    fn arbitrary_round_trip(ulid in any::<ULID>()) {
+      prop_assert_eq!(ulid.to_string().parse::<ULID>().unwrap(), ulid);
+    }
+
+    #[test]
+    fn same_timestamp_shares_timestamp(ulids in same_timestamp(1..16)) {
+      let timestamp = ulids[0].to_epoch_milli_as_long();
+      prop_assert!(ulids.iter().all(|ulid| ulid.to_epoch_milli_as_long() == timestamp));
+    }
+
+    #[test]
+    fn with_max_timestamp(ulid in with_timestamp(MAX_TIMESTAMP)) {
+      prop_assert_eq!(ulid.to_epoch_milli_as_long(), MAX_TIMESTAMP as i64);
+    }
+  }
+}
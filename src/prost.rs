@@ -0,0 +1,120 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [prost](https://github.com/tokio-rs/prost) messages for [ULID].
+//!
+//! The messages are defined in `proto/ulid.proto` so that other languages can share them:
+//!
+//! ```protobuf
+//! message ULID {
+//!   fixed64 most_significant_bits = 1;
+//!   fixed64 least_significant_bits = 2;
+//! }
+//!
+//! message ULIDBytes {
+//!   bytes value = 1;
+//! }
+//! ```
+//!
+//! # Examples
+//!
+//! ```
+//! use prost::Message;
+//! use ulid_generator_rs::prost::ULIDMessage;
+//! use ulid_generator_rs::ULID;
+//!
+//! let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+//! let encoded: Vec<u8> = ULIDMessage::from(ulid).encode_to_vec();
+//! let decoded: ULID = ULIDMessage::decode(encoded.as_slice()).unwrap().into();
+//! assert_eq!(decoded, ulid);
+//! ```
+
+use std::convert::TryFrom;
+
+use crate::{Endian, ULIDError, ULID};
+
+/// The `ulid.ULID` message, carrying a [ULID] as two `fixed64` fields.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, prost::Message)]
+pub struct ULIDMessage {
+  /// See [`ULID::most_significant_bits`].
+  #[prost(fixed64, tag = "1")]
+  pub most_significant_bits: u64,
+  /// See [`ULID::least_significant_bits`].
+  #[prost(fixed64, tag = "2")]
+  pub least_significant_bits: u64,
+}
+
+/// The `ulid.ULIDBytes` message, carrying a [ULID] as its 16 big-endian bytes.
+#[derive(Clone, PartialEq, Eq, Hash, prost::Message)]
+pub struct ULIDBytesMessage {
+  /// See [`ULID::to_byte_array`].
+  #[prost(bytes = "vec", tag = "1")]
+  pub value: Vec<u8>,
+}
+
+impl From<ULID> for ULIDMessage {
+  fn from(ulid: ULID) -> Self {
+    Self {
+      most_significant_bits: ulid.most_significant_bits(),
+      least_significant_bits: ulid.least_significant_bits(),
+    }
+  }
+}
+
+impl From<ULIDMessage> for ULID {
+  fn from(message: ULIDMessage) -> Self {
+    ULID::from((message.most_significant_bits, message.least_significant_bits))
+  }
+}
+
+impl From<ULID> for ULIDBytesMessage {
+  fn from(ulid: ULID) -> Self {
+    Self {
+      value: ulid.to_byte_array(Endian::BE),
+    }
+  }
+}
+
+impl TryFrom<ULIDBytesMessage> for ULID {
+  type Error = ULIDError;
+
+  fn try_from(message: ULIDBytesMessage) -> Result<Self, Self::Error> {
+    ULID::parse_from_byte_array(message.value, Endian::BE)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_BYTES};
+  use prost::Message;
+
+  #[test]
+  fn ulid_message() {
+    let encoded = ULIDMessage::from(GOLDEN).encode_to_vec();
+    let mut expected = vec![0x09];
+    expected.extend(GOLDEN_BYTES[..8].iter().rev());
+    expected.push(0x11);
+    expected.extend(GOLDEN_BYTES[8..].iter().rev());
+    assert_eq!(encoded, expected);
+    let decoded = ULIDMessage::decode(encoded.as_slice()).unwrap();
+    assert_eq!(ULID::from(decoded), GOLDEN);
+  }
+
+  #[test]
+  fn ulid_bytes_message() {
+    let encoded = ULIDBytesMessage::from(GOLDEN).encode_to_vec();
+    assert_eq!(encoded[..2], [0x0a, 16]);
+    assert_eq!(encoded[2..], GOLDEN_BYTES);
+    let decoded = ULIDBytesMessage::decode(encoded.as_slice()).unwrap();
+    assert_eq!(ULID::try_from(decoded), Ok(GOLDEN));
+
+    let invalid = ULIDBytesMessage { value: vec![1, 2, 3] };
+    assert_eq!(ULID::try_from(invalid), Err(ULIDError::InvalidByteArrayError));
+  }
+}
@@ -0,0 +1,64 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [quickcheck](https://github.com/BurntSushi/quickcheck) support.
+//!
+//! Arbitrary [ULID]s favor edge values: [ULID::MIN], [ULID::MAX], IDs whose randomness is all ones
+//! just before [ULID::increment] overflows, and IDs with the largest timestamp `2^48-1`.
+
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{MAX_TIMESTAMP, ULID};
+
+impl Arbitrary for ULID {
+  fn arbitrary(g: &mut Gen) -> Self {
+    ULID::arbitrary_from(u8::arbitrary(g), u64::arbitrary(g), u128::arbitrary(g))
+  }
+
+  fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+    Box::new(self.0.shrink().map(ULID::new))
+  }
+}
+
+/// [ULID]s that share one timestamp and vary in randomness.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SameTimestamp(pub Vec<ULID>);
+
+impl Arbitrary for SameTimestamp {
+  fn arbitrary(g: &mut Gen) -> Self {
+    let timestamp = u64::arbitrary(g) & MAX_TIMESTAMP;
+    let len = usize::arbitrary(g) % g.size().max(1) + 1;
+    let ulids = (0..len)
+      .map(|_| ULID::from_timestamp_and_randomness(timestamp, u128::arbitrary(g)))
+      .collect();
+    Self(ulids)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use quickcheck::quickcheck;
+
+  quickcheck! {
+    fn arbitrary_round_trip(ulid: ULID) -> bool {
+      ulid.to_string().parse::<ULID>() == Ok(ulid)
+    }
+
+    fn same_timestamp_shares_timestamp(ulids: SameTimestamp) -> bool {
+      let timestamp = ulids.0[0].to_epoch_milli_as_long();
+      ulids.0.iter().all(|ulid| ulid.to_epoch_milli_as_long() == timestamp)
+    }
+  }
+
+  #[test]
+  fn same_timestamp_with_zero_size() {
+    let ulids = SameTimestamp::arbitrary(&mut Gen::new(0));
+    assert_eq!(ulids.0.len(), 1);
+  }
+}
@@ -0,0 +1,109 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [redis](https://github.com/redis-rs/redis-rs) support.
+//!
+//! A [ULID] is written as its 26-character canonical string by default, or as its 16 big-endian bytes through
+//! [ULIDAsBytes]. Reading either type accepts both representations.
+
+use std::convert::TryFrom;
+
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+use crate::{append_crockford_u128, ULIDAsBytes, ULID, ULID_BYTES_LENGTH};
+
+fn decode(value: &Value) -> Result<ULID, ParsingError> {
+  let text = match value {
+    Value::BulkString(bytes) => {
+      if let Ok(bytes) = <[u8; ULID_BYTES_LENGTH as usize]>::try_from(bytes.as_slice()) {
+        return Ok(ULID::from(u128::from_be_bytes(bytes)));
+      }
+      std::str::from_utf8(bytes)?
+    }
+    Value::SimpleString(text) | Value::VerbatimString { text, .. } => text,
+    _ => return Err(format!("Response type not ULID compatible: {:?}", value).into()),
+  };
+  text
+    .parse::<ULID>()
+    .map_err(|e| format!("Value is not a valid ULID: {}", e).into())
+}
+
+impl ToRedisArgs for ULID {
+  fn write_redis_args<W>(&self, out: &mut W)
+  where
+    W: ?Sized + RedisWrite, {
+    out.write_arg(&append_crockford_u128(self.0));
+  }
+}
+
+impl ToSingleRedisArg for ULID {}
+
+impl FromRedisValue for ULID {
+  fn from_redis_value_ref(value: &Value) -> Result<Self, ParsingError> {
+    decode(value)
+  }
+
+  fn from_redis_value(value: Value) -> Result<Self, ParsingError> {
+    decode(&value)
+  }
+}
+
+impl ToRedisArgs for ULIDAsBytes {
+  fn write_redis_args<W>(&self, out: &mut W)
+  where
+    W: ?Sized + RedisWrite, {
+    out.write_arg(&u128::from(self.0).to_be_bytes());
+  }
+}
+
+impl ToSingleRedisArg for ULIDAsBytes {}
+
+impl FromRedisValue for ULIDAsBytes {
+  fn from_redis_value_ref(value: &Value) -> Result<Self, ParsingError> {
+    decode(value).map(ULIDAsBytes)
+  }
+
+  fn from_redis_value(value: Value) -> Result<Self, ParsingError> {
+    decode(&value).map(ULIDAsBytes)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_BYTES as BYTES, GOLDEN_STR as TEXT};
+
+  #[test]
+  fn to_redis_args() {
+    assert_eq!(GOLDEN.to_redis_args(), vec![TEXT.as_bytes().to_vec()]);
+    assert_eq!(ULIDAsBytes(GOLDEN).to_redis_args(), vec![BYTES.to_vec()]);
+  }
+
+  #[test]
+  fn from_redis_value() {
+    let values = [
+      Value::BulkString(TEXT.as_bytes().to_vec()),
+      Value::BulkString(TEXT.to_ascii_lowercase().into_bytes()),
+      Value::BulkString(BYTES.to_vec()),
+      Value::SimpleString(TEXT.to_string()),
+    ];
+    for value in values {
+      assert_eq!(ULID::from_redis_value_ref(&value), Ok(GOLDEN));
+      assert_eq!(ULIDAsBytes::from_redis_value(value), Ok(ULIDAsBytes(GOLDEN)));
+    }
+  }
+
+  #[test]
+  fn from_redis_value_error() {
+    assert!(ULID::from_redis_value(Value::Nil).is_err());
+    assert!(ULID::from_redis_value(Value::Int(1)).is_err());
+    assert!(ULID::from_redis_value(Value::BulkString(vec![0; 15])).is_err());
+    let error = ULID::from_redis_value(Value::BulkString(b"01ETGRM6448X1HM0PYWG2KT64U".to_vec())).unwrap_err();
+    assert!(error.to_string().contains("invalid the char: U"));
+  }
+}
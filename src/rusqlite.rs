@@ -0,0 +1,201 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [rusqlite](https://github.com/rusqlite/rusqlite) support.
+//!
+//! A [ULID] is stored as a 16-byte big-endian `BLOB` by default, or as `TEXT` through [ULIDAsText].
+//! Reading a [ULID] accepts either representation.
+//!
+//! [`register_functions`] additionally registers scalar SQL functions backed by a [ULIDGenerator].
+
+use std::convert::TryFrom;
+use std::sync::{Arc, Mutex};
+
+use rand::RngCore;
+use rusqlite::functions::FunctionFlags;
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::{Connection, Error};
+
+use crate::{Clock, Endian, ULIDAsText, ULIDGenerator, ULID, ULID_BYTES_LENGTH};
+
+impl ToSql for ULID {
+  fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+    Ok(ToSqlOutput::from(self.to_byte_array(Endian::BE)))
+  }
+}
+
+impl FromSql for ULID {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    match value {
+      ValueRef::Blob(bytes) => {
+        let bytes = <[u8; ULID_BYTES_LENGTH as usize]>::try_from(bytes).map_err(|_| FromSqlError::InvalidBlobSize {
+          expected_size: ULID_BYTES_LENGTH as usize,
+          blob_size: bytes.len(),
+        })?;
+        Ok(ULID::from(u128::from_be_bytes(bytes)))
+      }
+      ValueRef::Text(_) => ULIDAsText::column_result(value).map(ULID::from),
+      _ => Err(FromSqlError::InvalidType),
+    }
+  }
+}
+
+impl ToSql for ULIDAsText {
+  fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+    Ok(ToSqlOutput::from(self.0.to_string()))
+  }
+}
+
+impl FromSql for ULIDAsText {
+  fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+    let text = value.as_str()?;
+    let ulid = text.parse::<ULID>().map_err(|e| FromSqlError::Other(Box::new(e)))?;
+    Ok(Self(ulid))
+  }
+}
+
+/// Registers the ULID scalar SQL functions on `conn`.
+///
+/// - `ulid()` returns a new [ULID] as a `BLOB`.
+/// - `ulid_monotonic()` returns a new [ULID] as a `BLOB`, incremented from the previous `ulid_monotonic()` one in
+///   the same millisecond. `ulid()` does not affect it.
+/// - `ulid_timestamp(x)` returns the epoch time in milli seconds of a `BLOB` or `TEXT` [ULID].
+/// - `ulid_to_text(x)` converts a `BLOB` [ULID] to `TEXT`.
+/// - `ulid_from_text(x)` converts a `TEXT` [ULID] to `BLOB`.
+///
+/// # Example
+///
+/// ```rust
+/// use rusqlite::Connection;
+/// use ulid_generator_rs::rusqlite::register_functions;
+/// use ulid_generator_rs::{ULIDGenerator, ULID};
+///
+/// let conn = Connection::open_in_memory().unwrap();
+/// register_functions(&conn, ULIDGenerator::new()).unwrap();
+/// let ulid: ULID = conn.query_row("SELECT ulid()", [], |row| row.get(0)).unwrap();
+/// ```
+pub fn register_functions<R, C>(conn: &Connection, generator: ULIDGenerator<R, C>) -> rusqlite::Result<()>
+where
+  R: RngCore + Send + 'static,
+  C: Clock + Send + 'static, {
+  let state = Arc::new(Mutex::new((generator, None::<ULID>)));
+  let deterministic = FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC;
+
+  let generate_state = Arc::clone(&state);
+  conn.create_scalar_function("ulid", 0, FunctionFlags::SQLITE_UTF8, move |_| {
+    let mut guard = generate_state.lock().unwrap();
+    guard.0.generate().map_err(|e| Error::UserFunctionError(Box::new(e)))
+  })?;
+
+  conn.create_scalar_function("ulid_monotonic", 0, FunctionFlags::SQLITE_UTF8, move |_| {
+    let mut guard = state.lock().unwrap();
+    let (generator, previous_id) = &mut *guard;
+    let ulid = match previous_id {
+      Some(previous_id) => generator.generate_monotonic(previous_id),
+      None => generator.generate(),
+    }
+    .map_err(|e| Error::UserFunctionError(Box::new(e)))?;
+    *previous_id = Some(ulid);
+    Ok(ulid)
+  })?;
+
+  conn.create_scalar_function("ulid_timestamp", 1, deterministic, |ctx| {
+    let ulid = ctx.get::<Option<ULID>>(0)?;
+    Ok(ulid.map(|ulid| ulid.to_epoch_milli_as_long()))
+  })?;
+
+  conn.create_scalar_function("ulid_to_text", 1, deterministic, |ctx| {
+    let ulid = ctx.get::<Option<ULID>>(0)?;
+    Ok(ulid.map(ULIDAsText))
+  })?;
+
+  conn.create_scalar_function("ulid_from_text", 1, deterministic, |ctx| {
+    let ulid = ctx.get::<Option<ULIDAsText>>(0)?;
+    Ok(ulid.map(ULID::from))
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_BYTES, GOLDEN_EPOCH_MILLI, GOLDEN_STR};
+
+  #[test]
+  fn blob_and_text() -> rusqlite::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    conn.execute_batch("CREATE TABLE t (b BLOB NOT NULL, t TEXT NOT NULL)")?;
+    conn.execute("INSERT INTO t (b, t) VALUES (?1, ?2)", (GOLDEN, ULIDAsText(GOLDEN)))?;
+    let (b, t, raw): (ULID, ULID, Vec<u8>) = conn.query_row("SELECT b, t, b FROM t", [], |row| {
+      Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+    })?;
+    assert_eq!(b, GOLDEN);
+    assert_eq!(t, GOLDEN);
+    assert_eq!(raw, GOLDEN_BYTES);
+
+    let result = conn.query_row("SELECT x'0102'", [], |row| row.get::<_, ULID>(0));
+    assert!(matches!(result, Err(Error::FromSqlConversionFailure(..))));
+    Ok(())
+  }
+
+  #[test]
+  fn functions() -> rusqlite::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    register_functions(&conn, ULIDGenerator::new())?;
+
+    let (first, second): (ULID, ULID) = conn.query_row("SELECT ulid_monotonic(), ulid_monotonic()", [], |row| {
+      Ok((row.get(0)?, row.get(1)?))
+    })?;
+    assert!(first < second);
+
+    let (timestamp, text, blob): (i64, String, ULID) = conn.query_row(
+      "SELECT ulid_timestamp(?1), ulid_to_text(?1), ulid_from_text(?2)",
+      (GOLDEN, GOLDEN_STR),
+      |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+    )?;
+    assert_eq!(timestamp, GOLDEN.to_epoch_milli_as_long());
+    assert_eq!(text, GOLDEN_STR);
+    assert_eq!(blob, GOLDEN);
+
+    let timestamp: Option<i64> = conn.query_row("SELECT ulid_timestamp(NULL)", [], |row| row.get(0))?;
+    assert_eq!(timestamp, None);
+    let generated: ULID = conn.query_row("SELECT ulid()", [], |row| row.get(0))?;
+    assert_ne!(generated, GOLDEN);
+    Ok(())
+  }
+
+  #[test]
+  fn ulid_does_not_affect_monotonic() -> rusqlite::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    register_functions(&conn, ULIDGenerator::new())?;
+    for _ in 0..100 {
+      let (first, second): (ULID, ULID) =
+        conn.query_row("SELECT ulid_monotonic(), ulid(), ulid(), ulid_monotonic()", [], |row| {
+          Ok((row.get(0)?, row.get(3)?))
+        })?;
+      if first.to_epoch_milli_as_long() == second.to_epoch_milli_as_long() {
+        assert_eq!(second, first.increment());
+      } else {
+        assert!(first < second);
+      }
+    }
+    Ok(())
+  }
+
+  #[test]
+  fn functions_with_rng_and_clock() -> rusqlite::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    let generator = ULIDGenerator::with_rng_and_clock(rand::rngs::mock::StepRng::new(0, 1), || GOLDEN_EPOCH_MILLI);
+    register_functions(&conn, generator)?;
+    let (first, second): (ULID, ULID) = conn.query_row("SELECT ulid_monotonic(), ulid_monotonic()", [], |row| {
+      Ok((row.get(0)?, row.get(1)?))
+    })?;
+    assert_eq!(first.to_epoch_milli_as_long(), GOLDEN.to_epoch_milli_as_long());
+    assert_eq!(second, first.increment());
+    Ok(())
+  }
+}
@@ -0,0 +1,66 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [schemars](https://github.com/GREsau/schemars) support.
+//!
+//! A [ULID] is described as its 26-character canonical string.
+
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::{ulid_string_pattern, ULID, ULID_STRING_EXAMPLE, ULID_STRING_LENGTH};
+
+impl JsonSchema for ULID {
+  fn inline_schema() -> bool {
+    true
+  }
+
+  fn schema_name() -> Cow<'static, str> {
+    "ULID".into()
+  }
+
+  fn schema_id() -> Cow<'static, str> {
+    "ulid_generator_rs::ULID".into()
+  }
+
+  fn json_schema(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+      "type": "string",
+      "format": "ulid",
+      "minLength": ULID_STRING_LENGTH,
+      "maxLength": ULID_STRING_LENGTH,
+      "pattern": ulid_string_pattern(),
+      "examples": [ULID_STRING_EXAMPLE],
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::GOLDEN_STR;
+
+  #[test]
+  fn json_schema() {
+    let schema = schemars::schema_for!(ULID);
+    assert_eq!(
+      schema.as_value(),
+      &serde_json::json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "ULID",
+        "type": "string",
+        "format": "ulid",
+        "minLength": 26,
+        "maxLength": 26,
+        "pattern": "^[01234567][0123456789ABCDEFGHJKMNPQRSTVWXYZ]{25}$",
+        "examples": [GOLDEN_STR],
+      })
+    );
+  }
+}
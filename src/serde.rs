@@ -12,9 +12,14 @@
 //! canonical string representation as set by the ULID standard.
 //!
 //! ULIDs can optionally be serialized as u128 integers using the `ulid_as_u128`
-//! module. See the module's documentation for examples.
+//! module, or as 16 big-endian bytes using the `ulid_as_bytes` module.
+//! See the module's documentation for examples.
+//!
+//! Each alternate encoding module also provides `option`, `vec` and `map` submodules
+//! for `Option<ULID>`, `Vec<ULID>` and `HashMap<ULID, V>` fields respectively.
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "uuid")]
 use uuid::Uuid;
 
 use crate::ULID;
@@ -39,6 +44,99 @@ impl<'de> Deserialize<'de> for ULID {
   }
 }
 
+/// Generates the `option`, `vec` and `map` submodules of an alternate encoding module.
+///
+/// The enclosing module must provide `serialize` and `deserialize` functions for a bare [ULID].
+macro_rules! impl_collection_modules {
+  () => {
+    #[derive(PartialEq, Eq, Hash)]
+    struct Wrapper(ULID);
+
+    impl Serialize for Wrapper {
+      fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+      where
+        S: Serializer, {
+        serialize(&self.0, serializer)
+      }
+    }
+
+    impl<'de> Deserialize<'de> for Wrapper {
+      fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+      where
+        D: Deserializer<'de>, {
+        deserialize(deserializer).map(Wrapper)
+      }
+    }
+
+    /// Serialization and deserialization of `Option<ULID>`.
+    ///
+    /// Combine with `#[serde(default)]` to allow the field to be missing.
+    pub mod option {
+      use super::*;
+
+      /// Serializes an optional ULID.
+      pub fn serialize<S>(value: &Option<ULID>, serializer: S) -> Result<S::Ok, S::Error>
+      where
+        S: Serializer, {
+        value.map(Wrapper).serialize(serializer)
+      }
+
+      /// Deserializes an optional ULID.
+      pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<ULID>, D::Error>
+      where
+        D: Deserializer<'de>, {
+        let deserialized = Option::<Wrapper>::deserialize(deserializer)?;
+        Ok(deserialized.map(|wrapper| wrapper.0))
+      }
+    }
+
+    /// Serialization and deserialization of `Vec<ULID>`.
+    pub mod vec {
+      use super::*;
+
+      /// Serializes a sequence of ULIDs.
+      pub fn serialize<S>(values: &[ULID], serializer: S) -> Result<S::Ok, S::Error>
+      where
+        S: Serializer, {
+        serializer.collect_seq(values.iter().map(|value| Wrapper(*value)))
+      }
+
+      /// Deserializes a sequence of ULIDs.
+      pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<ULID>, D::Error>
+      where
+        D: Deserializer<'de>, {
+        let deserialized = Vec::<Wrapper>::deserialize(deserializer)?;
+        Ok(deserialized.into_iter().map(|wrapper| wrapper.0).collect())
+      }
+    }
+
+    /// Serialization and deserialization of `HashMap<ULID, V>` with ULID keys.
+    pub mod map {
+      use super::*;
+      use std::collections::HashMap;
+      use std::hash::BuildHasher;
+
+      /// Serializes a map keyed by ULIDs.
+      pub fn serialize<V, H, S>(values: &HashMap<ULID, V, H>, serializer: S) -> Result<S::Ok, S::Error>
+      where
+        V: Serialize,
+        S: Serializer, {
+        serializer.collect_map(values.iter().map(|(key, value)| (Wrapper(*key), value)))
+      }
+
+      /// Deserializes a map keyed by ULIDs.
+      pub fn deserialize<'de, V, H, D>(deserializer: D) -> Result<HashMap<ULID, V, H>, D::Error>
+      where
+        V: Deserialize<'de>,
+        H: BuildHasher + Default,
+        D: Deserializer<'de>, {
+        let deserialized = HashMap::<Wrapper, V, H>::deserialize(deserializer)?;
+        Ok(deserialized.into_iter().map(|(key, value)| (key.0, value)).collect())
+      }
+    }
+  };
+}
+
 /// Serialization and deserialization of ULIDs through their inner u128 type.
 ///
 /// To use it, annotate a field with
@@ -55,7 +153,11 @@ impl<'de> Deserialize<'de> for ULID {
 /// #[derive(Serialize, Deserialize)]
 /// struct U128Example {
 ///     #[serde(with = "ulid_as_u128")]
-///     identifier: ULID
+///     identifier: ULID,
+///     #[serde(with = "ulid_as_u128::option", default)]
+///     parent: Option<ULID>,
+///     #[serde(with = "ulid_as_u128::vec")]
+///     children: Vec<ULID>,
 /// }
 /// ```
 pub mod ulid_as_u128 {
@@ -75,6 +177,51 @@ pub mod ulid_as_u128 {
     let deserialized_u128 = u128::deserialize(deserializer)?;
     Ok(ULID::from(deserialized_u128))
   }
+
+  impl_collection_modules!();
+}
+
+/// Serialization and deserialization of ULIDs through their 16 big-endian bytes.
+///
+/// The bytes are serialized as a fixed-size array, so compact formats such as postcard
+/// write exactly 16 bytes, the same layout as the `borsh` and `bincode` features.
+///
+/// To use it, annotate a field with
+/// `#[serde(with = "ulid_as_bytes")]`,
+/// `#[serde(serialize_with = "ulid_as_bytes")]`, or
+/// `#[serde(deserialize_with = "ulid_as_bytes")]`.
+///
+/// # Examples
+///
+/// ```
+/// # use ulid_generator_rs::ULID;
+/// # use ulid_generator_rs::serde::ulid_as_bytes;
+/// # use serde::{Serialize, Deserialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct BytesExample {
+///     #[serde(with = "ulid_as_bytes")]
+///     identifier: ULID,
+/// }
+/// ```
+pub mod ulid_as_bytes {
+  use super::*;
+
+  /// Serializes a ULID as 16 big-endian bytes.
+  pub fn serialize<S>(value: &ULID, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer, {
+    value.0.to_be_bytes().serialize(serializer)
+  }
+
+  /// Deserializes a ULID from 16 big-endian bytes.
+  pub fn deserialize<'de, D>(deserializer: D) -> Result<ULID, D::Error>
+  where
+    D: Deserializer<'de>, {
+    let deserialized_bytes = <[u8; 16]>::deserialize(deserializer)?;
+    Ok(ULID::from(u128::from_be_bytes(deserialized_bytes)))
+  }
+
+  impl_collection_modules!();
 }
 
 /// Serialization and deserialization of ULIDs through UUID strings.
@@ -90,13 +237,16 @@ pub mod ulid_as_u128 {
 /// # use ulid_generator_rs::ULID;
 /// # use ulid_generator_rs::serde::ulid_as_uuid;
 /// # use serde::{Serialize, Deserialize};
+/// # use std::collections::HashMap;
 /// #[derive(Serialize, Deserialize)]
 /// struct UuidExample {
 ///     #[serde(with = "ulid_as_uuid")]
-///     identifier: ULID
+///     identifier: ULID,
+///     #[serde(with = "ulid_as_uuid::map")]
+///     names: HashMap<ULID, String>,
 /// }
 /// ```
-#[cfg(all(feature = "uuid", feature = "serde"))]
+#[cfg(feature = "uuid")]
 pub mod ulid_as_uuid {
   use super::*;
 
@@ -104,7 +254,7 @@ pub mod ulid_as_uuid {
   pub fn serialize<S>(value: &ULID, serializer: S) -> Result<S::Ok, S::Error>
   where
     S: Serializer, {
-    let uuid: Uuid = value.clone().into();
+    let uuid: Uuid = (*value).into();
     uuid.to_string().serialize(serializer)
   }
 
@@ -116,4 +266,94 @@ pub mod ulid_as_uuid {
     let de_uuid = Uuid::parse_str(&de_string).map_err(serde::de::Error::custom)?;
     Ok(ULID::from(de_uuid))
   }
+
+  impl_collection_modules!();
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_BYTES};
+  use std::collections::HashMap;
+
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct U128Collections {
+    #[serde(with = "ulid_as_u128::option", default)]
+    parent: Option<ULID>,
+    #[serde(with = "ulid_as_u128::vec")]
+    children: Vec<ULID>,
+    #[serde(with = "ulid_as_u128::map")]
+    names: HashMap<ULID, String>,
+  }
+
+  #[test]
+  fn ulid_as_u128_collections() {
+    let ulid: ULID = 1945530789360716160560926739305506752.into();
+    let expected = U128Collections {
+      parent: Some(ulid),
+      children: vec![ulid, ulid.increment()],
+      names: HashMap::from([(ulid, "a".to_string())]),
+    };
+    let json = serde_json::to_string(&expected).unwrap();
+    assert_eq!(
+      json,
+      r#"{"parent":1945530789360716160560926739305506752,"children":[1945530789360716160560926739305506752,1945530789360716160560926739305506753],"names":{"1945530789360716160560926739305506752":"a"}}"#
+    );
+    let actual: U128Collections = serde_json::from_str(&json).unwrap();
+    assert_eq!(actual, expected);
+
+    let actual: U128Collections = serde_json::from_str(r#"{"children":[],"names":{}}"#).unwrap();
+    assert_eq!(actual.parent, None);
+  }
+
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct BytesExample {
+    #[serde(with = "ulid_as_bytes")]
+    identifier: ULID,
+    #[serde(with = "ulid_as_bytes::vec")]
+    children: Vec<ULID>,
+  }
+
+  #[test]
+  fn ulid_as_bytes_postcard() {
+    let expected = BytesExample {
+      identifier: GOLDEN,
+      children: vec![GOLDEN],
+    };
+    let bytes = postcard::to_allocvec(&expected).unwrap();
+    let mut expected_bytes = GOLDEN_BYTES.to_vec();
+    expected_bytes.push(1);
+    expected_bytes.extend_from_slice(&GOLDEN_BYTES);
+    assert_eq!(bytes, expected_bytes);
+    let actual: BytesExample = postcard::from_bytes(&bytes).unwrap();
+    assert_eq!(actual, expected);
+  }
+
+  #[cfg(feature = "uuid")]
+  #[derive(Debug, PartialEq, Serialize, Deserialize)]
+  struct UuidCollections {
+    #[serde(with = "ulid_as_uuid::option")]
+    parent: Option<ULID>,
+    #[serde(with = "ulid_as_uuid::vec")]
+    children: Vec<ULID>,
+    #[serde(with = "ulid_as_uuid::map")]
+    names: HashMap<ULID, String>,
+  }
+
+  #[cfg(feature = "uuid")]
+  #[test]
+  fn ulid_as_uuid_collections() {
+    let expected = UuidCollections {
+      parent: None,
+      children: vec![GOLDEN],
+      names: HashMap::from([(GOLDEN, "a".to_string())]),
+    };
+    let json = serde_json::to_string(&expected).unwrap();
+    assert_eq!(
+      json,
+      r#"{"parent":null,"children":["0176a18a-1884-4743-1a02-dee4053d1888"],"names":{"0176a18a-1884-4743-1a02-dee4053d1888":"a"}}"#
+    );
+    let actual: UuidCollections = serde_json::from_str(&json).unwrap();
+    assert_eq!(actual, expected);
+  }
 }
@@ -0,0 +1,230 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [sqlx](https://github.com/launchbadge/sqlx) support.
+//!
+//! Enable the `sqlx-postgres`, `sqlx-sqlite` or `sqlx-mysql` feature for the target database.
+//!
+//! By default, a [ULID] is bound as its 16 big-endian bytes, so that the byte order of the column
+//! keeps `ORDER BY` consistent with `Ord for ULID`.
+//!
+//! | Database   | [ULID]       | [ULIDAsBytes] | [ULIDAsText] |
+//! |------------|--------------|---------------|--------------|
+//! | PostgreSQL | `uuid`       | `bytea`       | `text`       |
+//! | SQLite     | `BLOB`       | `BLOB`        | `TEXT`       |
+//! | MySQL      | `BINARY(16)` | `BINARY(16)`  | `CHAR(26)`   |
+//!
+//! Decoding a [ULID] accepts any of these column types.
+
+use std::convert::TryFrom;
+
+use sqlx::database::Database;
+use sqlx::decode::Decode;
+use sqlx::encode::{Encode, IsNull};
+use sqlx::error::BoxDynError;
+use sqlx::types::Type;
+use sqlx::ValueRef;
+
+use crate::{Endian, ULIDAsBytes, ULIDAsText, ULIDError, ULID, ULID_BYTES_LENGTH};
+
+fn decode_bytes(bytes: &[u8]) -> Result<ULID, ULIDError> {
+  let bytes = <[u8; ULID_BYTES_LENGTH as usize]>::try_from(bytes).map_err(|_| ULIDError::InvalidByteArrayError)?;
+  Ok(ULID::from(u128::from_be_bytes(bytes)))
+}
+
+#[cfg(feature = "sqlx-postgres")]
+impl Type<sqlx::Postgres> for ULID {
+  fn type_info() -> sqlx::postgres::PgTypeInfo {
+    // The OID of `uuid`.
+    sqlx::postgres::PgTypeInfo::with_oid(sqlx::postgres::types::Oid(2950))
+  }
+
+  fn compatible(ty: &sqlx::postgres::PgTypeInfo) -> bool {
+    *ty == <Self as Type<sqlx::Postgres>>::type_info()
+      || <[u8] as Type<sqlx::Postgres>>::compatible(ty)
+      || <str as Type<sqlx::Postgres>>::compatible(ty)
+  }
+}
+
+#[cfg(feature = "sqlx-sqlite")]
+impl Type<sqlx::Sqlite> for ULID {
+  fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+    <[u8] as Type<sqlx::Sqlite>>::type_info()
+  }
+
+  fn compatible(ty: &sqlx::sqlite::SqliteTypeInfo) -> bool {
+    <[u8] as Type<sqlx::Sqlite>>::compatible(ty) || <str as Type<sqlx::Sqlite>>::compatible(ty)
+  }
+}
+
+#[cfg(feature = "sqlx-mysql")]
+impl Type<sqlx::MySql> for ULID {
+  fn type_info() -> sqlx::mysql::MySqlTypeInfo {
+    <[u8] as Type<sqlx::MySql>>::type_info()
+  }
+
+  fn compatible(ty: &sqlx::mysql::MySqlTypeInfo) -> bool {
+    <[u8] as Type<sqlx::MySql>>::compatible(ty) || <str as Type<sqlx::MySql>>::compatible(ty)
+  }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for ULID
+where
+  Vec<u8>: Encode<'q, DB>,
+{
+  fn encode_by_ref(&self, buf: &mut <DB as Database>::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+    self.to_byte_array(Endian::BE).encode(buf)
+  }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for ULID
+where
+  &'r [u8]: Decode<'r, DB>,
+  &'r str: Decode<'r, DB>,
+  str: Type<DB>,
+{
+  fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+    let is_text = <str as Type<DB>>::compatible(&value.type_info());
+    if is_text {
+      let text = <&str as Decode<DB>>::decode(value)?;
+      Ok(text.parse::<ULID>()?)
+    } else {
+      let bytes = <&[u8] as Decode<DB>>::decode(value)?;
+      Ok(decode_bytes(bytes)?)
+    }
+  }
+}
+
+impl<DB: Database> Type<DB> for ULIDAsBytes
+where
+  [u8]: Type<DB>,
+{
+  fn type_info() -> <DB as Database>::TypeInfo {
+    <[u8] as Type<DB>>::type_info()
+  }
+
+  fn compatible(ty: &<DB as Database>::TypeInfo) -> bool {
+    <[u8] as Type<DB>>::compatible(ty)
+  }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for ULIDAsBytes
+where
+  Vec<u8>: Encode<'q, DB>,
+{
+  fn encode_by_ref(&self, buf: &mut <DB as Database>::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+    self.0.to_byte_array(Endian::BE).encode(buf)
+  }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for ULIDAsBytes
+where
+  &'r [u8]: Decode<'r, DB>,
+{
+  fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+    let bytes = <&[u8] as Decode<DB>>::decode(value)?;
+    Ok(Self(decode_bytes(bytes)?))
+  }
+}
+
+impl<DB: Database> Type<DB> for ULIDAsText
+where
+  str: Type<DB>,
+{
+  fn type_info() -> <DB as Database>::TypeInfo {
+    <str as Type<DB>>::type_info()
+  }
+
+  fn compatible(ty: &<DB as Database>::TypeInfo) -> bool {
+    <str as Type<DB>>::compatible(ty)
+  }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for ULIDAsText
+where
+  String: Encode<'q, DB>,
+{
+  fn encode_by_ref(&self, buf: &mut <DB as Database>::ArgumentBuffer<'q>) -> Result<IsNull, BoxDynError> {
+    self.0.to_string().encode(buf)
+  }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for ULIDAsText
+where
+  &'r str: Decode<'r, DB>,
+{
+  fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+    let text = <&str as Decode<DB>>::decode(value)?;
+    Ok(Self(text.parse::<ULID>()?))
+  }
+}
+
+#[cfg(all(test, feature = "sqlx-sqlite"))]
+mod tests {
+  use super::*;
+  use crate::fixtures::ulids;
+  use sqlx::{Connection, SqliteConnection};
+
+  #[tokio::test]
+  async fn blob_order_by() -> Result<(), sqlx::Error> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE t (id BLOB NOT NULL)")
+      .execute(&mut conn)
+      .await?;
+    let mut expected = ulids();
+    for ulid in &expected {
+      sqlx::query("INSERT INTO t (id) VALUES (?)")
+        .bind(ulid)
+        .execute(&mut conn)
+        .await?;
+    }
+    let actual: Vec<ULID> = sqlx::query_scalar("SELECT id FROM t ORDER BY id")
+      .fetch_all(&mut conn)
+      .await?;
+    expected.sort();
+    assert_eq!(actual, expected);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn text_order_by() -> Result<(), sqlx::Error> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    sqlx::query("CREATE TABLE t (id TEXT NOT NULL)")
+      .execute(&mut conn)
+      .await?;
+    let mut expected = ulids();
+    for ulid in &expected {
+      sqlx::query("INSERT INTO t (id) VALUES (?)")
+        .bind(ULIDAsText(*ulid))
+        .execute(&mut conn)
+        .await?;
+    }
+    let actual: Vec<ULIDAsText> = sqlx::query_scalar("SELECT id FROM t ORDER BY id")
+      .fetch_all(&mut conn)
+      .await?;
+    expected.sort();
+    assert_eq!(actual.into_iter().map(ULID::from).collect::<Vec<_>>(), expected);
+
+    // A plain ULID also decodes from a TEXT column.
+    let first: ULID = sqlx::query_scalar("SELECT id FROM t ORDER BY id LIMIT 1")
+      .fetch_one(&mut conn)
+      .await?;
+    assert_eq!(first, expected[0]);
+    Ok(())
+  }
+
+  #[tokio::test]
+  async fn invalid_blob() -> Result<(), sqlx::Error> {
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await?;
+    let result = sqlx::query_scalar::<_, ULID>("SELECT x'0102'")
+      .fetch_one(&mut conn)
+      .await;
+    assert!(matches!(result, Err(sqlx::Error::ColumnDecode { .. })));
+    Ok(())
+  }
+}
@@ -0,0 +1,55 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [time](https://github.com/time-rs/time) support.
+//!
+//! A `time::OffsetDateTime` can also be passed to [`ULIDGenerator::generate_at`](crate::ULIDGenerator::generate_at).
+
+use time::{Duration, OffsetDateTime};
+
+use crate::ULID;
+
+impl ULID {
+  /// Converts a [ULID] to an [OffsetDateTime] in UTC.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use time::OffsetDateTime;
+  /// use ulid_generator_rs::{ULIDGenerator, ULID};
+  ///
+  /// let mut generator: ULIDGenerator = ULIDGenerator::new();
+  /// let ulid: ULID = generator.generate().unwrap();
+  /// let date_time: OffsetDateTime = ulid.to_offset_datetime();
+  /// ```
+  #[must_use]
+  pub fn to_offset_datetime(&self) -> OffsetDateTime {
+    OffsetDateTime::UNIX_EPOCH + Duration::milliseconds(self.to_epoch_milli_as_long())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::fixtures::{GOLDEN, GOLDEN_EPOCH_MILLI};
+  use crate::{ULIDGenerator, ULID};
+  use time::OffsetDateTime;
+
+  #[test]
+  fn to_offset_datetime() {
+    let expected = OffsetDateTime::from_unix_timestamp_nanos(GOLDEN_EPOCH_MILLI as i128 * 1_000_000).unwrap();
+    assert_eq!(GOLDEN.to_offset_datetime(), expected);
+    assert_eq!(ULID::MAX.to_offset_datetime().year(), 10889);
+  }
+
+  #[test]
+  fn generate_at() {
+    let date_time = OffsetDateTime::from_unix_timestamp(1_609_459_200).unwrap();
+    let ulid = ULIDGenerator::new().generate_at(date_time).unwrap();
+    assert_eq!(ulid.to_offset_datetime(), date_time);
+  }
+}
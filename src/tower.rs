@@ -0,0 +1,366 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [tower](https://github.com/tower-rs/tower) support.
+//!
+//! [RequestIdLayer] stamps every request with a [ULID] in the `x-request-id` header, keeping an incoming one
+//! that parses as a [ULID], and exposes it to inner services as the [RequestId] request extension.
+//!
+//! ```rust
+//! use tower::ServiceBuilder;
+//! use ulid_generator_rs::tower::RequestIdLayer;
+//!
+//! let layer = RequestIdLayer::new().monotonic(true);
+//! # let _ = ServiceBuilder::new().layer(layer);
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use http::header::{HeaderName, HeaderValue};
+use http::Request;
+use pin_project_lite::pin_project;
+use rand::RngCore;
+use tower::{BoxError, Layer, Service};
+
+use crate::{Clock, SystemClock, ThreadLocalRng, ULIDError, ULIDGenerator, ULID};
+
+/// The default header, `x-request-id`.
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+/// The [ULID] of a request, stored in the request extensions by [RequestIdService].
+#[derive(Debug, Copy, Clone, Hash, Eq, Ord, PartialEq, PartialOrd)]
+pub struct RequestId(pub ULID);
+
+impl From<ULID> for RequestId {
+  fn from(ulid: ULID) -> Self {
+    Self(ulid)
+  }
+}
+
+impl From<RequestId> for ULID {
+  fn from(value: RequestId) -> Self {
+    value.0
+  }
+}
+
+type State<R, C> = Arc<Mutex<(ULIDGenerator<R, C>, Option<ULID>)>>;
+
+/// The [Layer] of [RequestIdService].
+///
+/// The services created by a layer and by its clones share one [ULIDGenerator] and previous id, so
+/// monotonic ids increase across all of them.
+#[derive(Debug)]
+pub struct RequestIdLayer<R = ThreadLocalRng, C = SystemClock> {
+  header_name: HeaderName,
+  monotonic: bool,
+  state: State<R, C>,
+}
+
+impl RequestIdLayer {
+  /// The Constructor for [RequestIdLayer], stamping [X_REQUEST_ID] with non-monotonic [ULID]s.
+  #[must_use]
+  pub fn new() -> Self {
+    Self::with_generator(ULIDGenerator::new())
+  }
+}
+
+impl<R, C> RequestIdLayer<R, C> {
+  /// The Constructor for [RequestIdLayer] with the given [ULIDGenerator].
+  #[must_use]
+  pub fn with_generator(generator: ULIDGenerator<R, C>) -> Self {
+    Self {
+      header_name: X_REQUEST_ID,
+      monotonic: false,
+      state: Arc::new(Mutex::new((generator, None))),
+    }
+  }
+
+  /// Sets the header that carries the request id.
+  #[must_use]
+  pub fn header_name(mut self, header_name: HeaderName) -> Self {
+    self.header_name = header_name;
+    self
+  }
+
+  /// Generates each [ULID] with [`ULIDGenerator::generate_monotonic`] from the previous one.
+  #[must_use]
+  pub fn monotonic(mut self, monotonic: bool) -> Self {
+    self.monotonic = monotonic;
+    self
+  }
+}
+
+impl Default for RequestIdLayer {
+  fn default() -> Self {
+    RequestIdLayer::new()
+  }
+}
+
+impl<R, C> Clone for RequestIdLayer<R, C> {
+  fn clone(&self) -> Self {
+    Self {
+      header_name: self.header_name.clone(),
+      monotonic: self.monotonic,
+      state: Arc::clone(&self.state),
+    }
+  }
+}
+
+impl<S, R, C> Layer<S> for RequestIdLayer<R, C> {
+  type Service = RequestIdService<S, R, C>;
+
+  fn layer(&self, inner: S) -> Self::Service {
+    RequestIdService {
+      inner,
+      header_name: self.header_name.clone(),
+      monotonic: self.monotonic,
+      state: Arc::clone(&self.state),
+    }
+  }
+}
+
+/// The [Service] that stamps requests with a [ULID]. See [RequestIdLayer].
+///
+/// Its error is a [BoxError], holding either the error of the inner service or the [ULIDError] of a
+/// request that no id could be generated for. With axum, handle it with `axum::error_handling::HandleErrorLayer`.
+#[derive(Debug)]
+pub struct RequestIdService<S, R = ThreadLocalRng, C = SystemClock> {
+  inner: S,
+  header_name: HeaderName,
+  monotonic: bool,
+  state: State<R, C>,
+}
+
+impl<S: Clone, R, C> Clone for RequestIdService<S, R, C> {
+  fn clone(&self) -> Self {
+    Self {
+      inner: self.inner.clone(),
+      header_name: self.header_name.clone(),
+      monotonic: self.monotonic,
+      state: Arc::clone(&self.state),
+    }
+  }
+}
+
+impl<S, R, C> RequestIdService<S, R, C>
+where
+  R: RngCore,
+  C: Clock,
+{
+  /// Generates the id of a request without a valid one.
+  ///
+  /// If the clock fails, or went backwards in monotonic mode, continues from the previous id with
+  /// [`ULIDGenerator::generate_next`]. Returns an error only if there is no previous id or it is [`ULID::MAX`].
+  fn next_id(&self) -> Result<ULID, ULIDError> {
+    let mut guard = self.state.lock().unwrap();
+    let (generator, previous_id) = &mut *guard;
+    let ulid = match *previous_id {
+      Some(previous_id) if self.monotonic => generator.generate_next(&previous_id)?,
+      Some(previous_id) => generator
+        .generate()
+        .or_else(|_| generator.generate_next(&previous_id))?,
+      None => generator.generate()?,
+    };
+    *previous_id = Some(ulid);
+    Ok(ulid)
+  }
+}
+
+impl<S, R, C, B> Service<Request<B>> for RequestIdService<S, R, C>
+where
+  S: Service<Request<B>>,
+  S::Error: Into<BoxError>,
+  R: RngCore,
+  C: Clock,
+{
+  type Error = BoxError;
+  type Future = ResponseFuture<S::Future>;
+  type Response = S::Response;
+
+  fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+    self.inner.poll_ready(cx).map_err(Into::into)
+  }
+
+  fn call(&mut self, mut request: Request<B>) -> Self::Future {
+    let incoming_id = request
+      .headers()
+      .get(&self.header_name)
+      .and_then(|value| value.to_str().ok())
+      .and_then(|value| value.parse::<ULID>().ok());
+    let ulid = match incoming_id {
+      Some(ulid) => ulid,
+      None => {
+        let ulid = match self.next_id() {
+          Ok(ulid) => ulid,
+          Err(e) => return ResponseFuture::error(e),
+        };
+        let value = HeaderValue::try_from(ulid.to_string()).expect("a ULID is a valid header value");
+        request.headers_mut().insert(self.header_name.clone(), value);
+        ulid
+      }
+    };
+    request.extensions_mut().insert(RequestId(ulid));
+    ResponseFuture::inner(self.inner.call(request))
+  }
+}
+
+pin_project! {
+  /// The [Future] of [RequestIdService] responses.
+  pub struct ResponseFuture<F> {
+    #[pin]
+    kind: Kind<F>,
+  }
+}
+
+pin_project! {
+  #[project = KindProj]
+  enum Kind<F> {
+    Inner { #[pin] future: F },
+    Error { error: Option<ULIDError> },
+  }
+}
+
+impl<F> ResponseFuture<F> {
+  fn inner(future: F) -> Self {
+    Self {
+      kind: Kind::Inner { future },
+    }
+  }
+
+  fn error(error: ULIDError) -> Self {
+    Self {
+      kind: Kind::Error { error: Some(error) },
+    }
+  }
+}
+
+impl<F, T, E> Future for ResponseFuture<F>
+where
+  F: Future<Output = Result<T, E>>,
+  E: Into<BoxError>,
+{
+  type Output = Result<T, BoxError>;
+
+  fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+    match self.project().kind.project() {
+      KindProj::Inner { future } => future.poll(cx).map_err(Into::into),
+      KindProj::Error { error } => Poll::Ready(Err(error.take().expect("polled after completion").into())),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_EPOCH_MILLI, GOLDEN_STR};
+  use std::convert::Infallible;
+  use std::sync::atomic::{AtomicU64, Ordering};
+  use tower::{service_fn, ServiceExt};
+
+  async fn echo(request: Request<()>) -> Result<(Option<String>, Option<RequestId>), Infallible> {
+    let header = request
+      .headers()
+      .get("x-request-id")
+      .map(|value| value.to_str().unwrap().to_string());
+    let header = header.or_else(|| {
+      request
+        .headers()
+        .get("x-correlation-id")
+        .map(|value| value.to_str().unwrap().to_string())
+    });
+    Ok((header, request.extensions().get::<RequestId>().copied()))
+  }
+
+  #[tokio::test]
+  async fn stamps_requests() {
+    let service = RequestIdLayer::new().layer(service_fn(echo));
+    let (header, request_id) = service.oneshot(Request::new(())).await.unwrap();
+    let request_id = request_id.unwrap();
+    assert_eq!(header, Some(request_id.0.to_string()));
+  }
+
+  #[tokio::test]
+  async fn keeps_valid_incoming_ids() {
+    let service = RequestIdLayer::new().layer(service_fn(echo));
+    let request = Request::builder().header("x-request-id", GOLDEN_STR).body(()).unwrap();
+    let (header, request_id) = service.clone().oneshot(request).await.unwrap();
+    assert_eq!(header.as_deref(), Some(GOLDEN_STR));
+    assert_eq!(request_id, Some(RequestId(GOLDEN)));
+
+    let request = Request::builder()
+      .header("x-request-id", "not-a-ulid")
+      .body(())
+      .unwrap();
+    let (header, request_id) = service.oneshot(request).await.unwrap();
+    let request_id = request_id.unwrap();
+    assert_eq!(header, Some(request_id.0.to_string()));
+  }
+
+  #[tokio::test]
+  async fn header_name_and_monotonic() {
+    let layer = RequestIdLayer::new()
+      .header_name(HeaderName::from_static("x-correlation-id"))
+      .monotonic(true);
+    let service = layer.layer(service_fn(echo));
+    let mut previous_id = None;
+    for _ in 0..100 {
+      let (header, request_id) = service.clone().oneshot(Request::new(())).await.unwrap();
+      let request_id = request_id.unwrap().0;
+      assert_eq!(header, Some(request_id.to_string()));
+      if let Some(previous_id) = previous_id {
+        assert!(previous_id < request_id);
+      }
+      previous_id = Some(request_id);
+    }
+  }
+
+  #[tokio::test]
+  async fn backwards_clock() {
+    let now = Arc::new(AtomicU64::new(GOLDEN_EPOCH_MILLI));
+    let clock = {
+      let now = Arc::clone(&now);
+      move || now.fetch_sub(1, Ordering::SeqCst)
+    };
+    let layer =
+      RequestIdLayer::with_generator(ULIDGenerator::with_rng_and_clock(ThreadLocalRng, clock)).monotonic(true);
+    let mut previous_id: Option<ULID> = None;
+    for _ in 0..10 {
+      let (_, request_id) = layer.layer(service_fn(echo)).oneshot(Request::new(())).await.unwrap();
+      let request_id = request_id.unwrap().0;
+      if let Some(previous_id) = previous_id {
+        assert_eq!(request_id, previous_id.increment());
+      }
+      previous_id = Some(request_id);
+    }
+  }
+
+  #[tokio::test]
+  async fn out_of_range_clock() {
+    let now = Arc::new(AtomicU64::new(u64::MAX));
+    let clock = {
+      let now = Arc::clone(&now);
+      move || now.load(Ordering::SeqCst)
+    };
+    let layer = RequestIdLayer::with_generator(ULIDGenerator::with_rng_and_clock(ThreadLocalRng, clock));
+    let service = layer.layer(service_fn(echo));
+    let error = service.clone().oneshot(Request::new(())).await.unwrap_err();
+    assert_eq!(
+      error.downcast_ref::<ULIDError>(),
+      Some(&ULIDError::TimestampOverflowError)
+    );
+
+    now.store(GOLDEN_EPOCH_MILLI, Ordering::SeqCst);
+    let (_, first) = service.clone().oneshot(Request::new(())).await.unwrap();
+    now.store(u64::MAX, Ordering::SeqCst);
+    let (_, second) = service.oneshot(Request::new(())).await.unwrap();
+    assert_eq!(second.unwrap().0, first.unwrap().0.increment());
+  }
+}
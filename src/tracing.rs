@@ -0,0 +1,98 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [tracing](https://github.com/tokio-rs/tracing) support.
+//!
+//! `tracing::Value` is sealed, so a [ULID] is recorded through [`ULID::as_tracing_value`], which formats the
+//! canonical string without allocating. To record the timestamp as well, enable the `valuable` feature of
+//! this crate and of tracing, build with `--cfg tracing_unstable`, and record `tracing::field::valuable(&ulid)`.
+
+use tracing::field::{display, DisplayValue};
+
+use crate::ULID;
+
+impl ULID {
+  /// Returns a [tracing::Value] recording this [ULID] as its canonical string.
+  ///
+  /// # Example
+  ///
+  /// ```rust
+  /// use ulid_generator_rs::ULID;
+  ///
+  /// let ulid: ULID = "01ETGRM6448X1HM0PYWG2KT648".parse().unwrap();
+  /// tracing::info!(ulid = ulid.as_tracing_value(), ulid.timestamp = ulid.to_epoch_milli_as_long(), "created");
+  /// ```
+  pub fn as_tracing_value(&self) -> DisplayValue<ULID> {
+    display(*self)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use crate::fixtures::{GOLDEN, GOLDEN_EPOCH_MILLI, GOLDEN_STR};
+  use std::fmt;
+  use std::sync::{Arc, Mutex};
+  use tracing::field::{Field, Visit};
+  use tracing::span::{Attributes, Id, Record};
+  use tracing::{Event, Metadata, Subscriber};
+
+  /// Collects the fields of events as `name=value`.
+  struct Fields(Arc<Mutex<Vec<String>>>);
+
+  impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+      self.0.lock().unwrap().push(format!("{}={:?}", field.name(), value));
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+      self.0.lock().unwrap().push(format!("{}={}", field.name(), value));
+    }
+  }
+
+  struct CollectingSubscriber(Arc<Mutex<Vec<String>>>);
+
+  impl Subscriber for CollectingSubscriber {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+      true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+      Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+      event.record(&mut Fields(Arc::clone(&self.0)));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+  }
+
+  #[test]
+  fn as_tracing_value() {
+    let fields = Arc::new(Mutex::new(Vec::new()));
+    tracing::subscriber::with_default(CollectingSubscriber(Arc::clone(&fields)), || {
+      tracing::info!(
+        ulid = GOLDEN.as_tracing_value(),
+        ulid.timestamp = GOLDEN.to_epoch_milli_as_long()
+      );
+    });
+    assert_eq!(
+      *fields.lock().unwrap(),
+      vec![
+        format!("ulid={}", GOLDEN_STR),
+        format!("ulid.timestamp={}", GOLDEN_EPOCH_MILLI)
+      ]
+    );
+  }
+}
@@ -0,0 +1,15 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Conversions between [ULID] and [`ulid::Ulid`](https://github.com/dylanhart/ulid-rs).
+//!
+//! Both types hold the same 128-bit value, so the conversions keep the string form and the ordering.
+
+use ulid::Ulid;
+
+impl_u128_ulid_conversions!(Ulid);
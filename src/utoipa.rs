@@ -0,0 +1,61 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [utoipa](https://github.com/juhaku/utoipa) support.
+//!
+//! A [ULID] is described as its 26-character canonical string.
+
+use std::borrow::Cow;
+
+use utoipa::openapi::schema::{ObjectBuilder, Schema, SchemaFormat, SchemaType, Type};
+use utoipa::openapi::RefOr;
+use utoipa::{PartialSchema, ToSchema};
+
+use crate::{ulid_string_pattern, ULID, ULID_STRING_EXAMPLE, ULID_STRING_LENGTH};
+
+impl PartialSchema for ULID {
+  fn schema() -> RefOr<Schema> {
+    ObjectBuilder::new()
+      .schema_type(SchemaType::Type(Type::String))
+      .format(Some(SchemaFormat::Custom("ulid".to_string())))
+      .min_length(Some(ULID_STRING_LENGTH as usize))
+      .max_length(Some(ULID_STRING_LENGTH as usize))
+      .pattern(Some(ulid_string_pattern()))
+      .examples([ULID_STRING_EXAMPLE])
+      .into()
+  }
+}
+
+impl ToSchema for ULID {
+  fn name() -> Cow<'static, str> {
+    "ULID".into()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::GOLDEN_STR;
+
+  #[test]
+  fn schema() {
+    let schema = serde_json::to_value(ULID::schema()).unwrap();
+    assert_eq!(
+      schema,
+      serde_json::json!({
+        "type": "string",
+        "format": "ulid",
+        "minLength": 26,
+        "maxLength": 26,
+        "pattern": "^[01234567][0123456789ABCDEFGHJKMNPQRSTVWXYZ]{25}$",
+        "examples": [GOLDEN_STR],
+      })
+    );
+    assert_eq!(ULID::name(), "ULID");
+  }
+}
@@ -0,0 +1,58 @@
+// Copyright 2020 Developers of the `ulid-generator-rs` project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! [valuable](https://github.com/tokio-rs/valuable) support.
+//!
+//! A [ULID] is a structure with the fields `ulid`, its canonical string, and `timestamp`, its epoch milli
+//! seconds. Neither allocates. With the `valuable` feature of tracing and `--cfg tracing_unstable`, record it
+//! in tracing with `tracing::field::valuable(&ulid)`.
+
+use valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+use crate::{append_crockford_u128, ULID};
+
+static FIELDS: &[NamedField<'static>] = &[NamedField::new("ulid"), NamedField::new("timestamp")];
+
+impl Valuable for ULID {
+  fn as_value(&self) -> Value<'_> {
+    Value::Structable(self)
+  }
+
+  fn visit(&self, visit: &mut dyn Visit) {
+    let encoded = append_crockford_u128(self.0);
+    let values = [
+      Value::String(core::str::from_utf8(&encoded).unwrap()),
+      Value::I64(self.to_epoch_milli_as_long()),
+    ];
+    visit.visit_named_fields(&NamedValues::new(FIELDS, &values));
+  }
+}
+
+impl Structable for ULID {
+  fn definition(&self) -> StructDef<'_> {
+    StructDef::new_static("ULID", Fields::Named(FIELDS))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::fixtures::{GOLDEN, GOLDEN_EPOCH_MILLI, GOLDEN_STR};
+  use alloc::format;
+
+  #[test]
+  fn fields() {
+    assert_eq!(
+      format!("{:?}", GOLDEN.as_value()),
+      format!(
+        r#"ULID {{ ulid: "{}", timestamp: {} }}"#,
+        GOLDEN_STR, GOLDEN_EPOCH_MILLI
+      )
+    );
+  }
+}